@@ -0,0 +1,105 @@
+// Borrowing versus copying follower records
+//
+// Times followers::Followers, which borrows every name from the text, against
+// followers::parse_owned, which copies every name into a String. Run it with
+//
+//     cargo bench --bench followers [records]
+//
+// Each parser reads the same generated list a few times and the fastest run is reported, along
+// with the throughput in MB/s.
+
+use practice_one::followers::{self, Followers};
+use std::{
+    env,
+    fmt::Write as _,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+const RUNS: usize = 10;
+
+fn follower_list(records: usize) -> String {
+    let first_names = ["script", "ferris", "grace", "ada", "linus", "barbara"];
+    let last_names = ["saga", "crab", "hopper", "lovelace", "torvalds", "liskov"];
+
+    let mut text = String::from("# first name, last name\n");
+    for index in 0..records {
+        writeln!(
+            text,
+            "{}{}, {}",
+            first_names[index % first_names.len()],
+            index,
+            last_names[index / first_names.len() % last_names.len()]
+        )
+        .unwrap();
+    }
+    text
+}
+
+// Fastest of RUNS runs of f
+fn fastest(mut f: impl FnMut() -> usize) -> (Duration, usize) {
+    let mut best = Duration::MAX;
+    let mut count = 0;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        count = black_box(f());
+        best = best.min(start.elapsed());
+    }
+    (best, count)
+}
+
+fn report(name: &str, bytes: usize, (time, count): (Duration, usize)) {
+    let megabytes = bytes as f64 / 1_000_000.0;
+    println!(
+        "{:<28}{:>10.2?}  {:>8.1} MB/s  {} followers",
+        name,
+        time,
+        megabytes / time.as_secs_f64(),
+        count
+    );
+}
+
+fn main() {
+    // cargo bench passes --bench, anything that parses as a number is the record count
+    let records = env::args()
+        .skip(1)
+        .find_map(|arg| arg.parse().ok())
+        .unwrap_or(200_000);
+    let text = follower_list(records);
+    println!(
+        "{} records, {:.1} MB, fastest of {} runs",
+        records,
+        text.len() as f64 / 1_000_000.0,
+        RUNS
+    );
+
+    report(
+        "borrowed (Followers)",
+        text.len(),
+        fastest(|| {
+            Followers::new(black_box(&text))
+                .map(|follower| follower.expect("generated records are valid"))
+                .map(|follower| follower.first_name.len() + follower.last_name.len())
+                .filter(|length| *length > 0)
+                .count()
+        }),
+    );
+    report(
+        "borrowed, collected",
+        text.len(),
+        fastest(|| {
+            followers::parse(black_box(&text), followers::DEFAULT_DELIMITER)
+                .expect("generated records are valid")
+                .len()
+        }),
+    );
+    report(
+        "owned (parse_owned)",
+        text.len(),
+        fastest(|| {
+            followers::parse_owned(black_box(&text), followers::DEFAULT_DELIMITER)
+                .expect("generated records are valid")
+                .len()
+        }),
+    );
+}
@@ -0,0 +1,21 @@
+// Exercise for tut10: building and querying a HashMap
+//
+// Replace every todo!() below, then run `cargo run -- check tut10_followers_map`.
+
+use std::collections::HashMap;
+
+// Give every follower an id in the order they come in, starting at 0:
+// ["one", "two"] => {0: "one", 1: "two"}
+pub fn build_followers(names: &[&str]) -> HashMap<i32, String> {
+    todo!()
+}
+
+// Ids of the followers whose name starts with `prefix`, sorted from small to big
+pub fn ids_with_prefix(followers: &HashMap<i32, String>, prefix: &str) -> Vec<i32> {
+    todo!()
+}
+
+// Removes a follower and returns its name, or None when the id doesn't exist
+pub fn unfollow(followers: &mut HashMap<i32, String>, id: i32) -> Option<String> {
+    todo!()
+}
@@ -0,0 +1,25 @@
+// Reference solution for tut10_followers_map, try the exercise before reading it
+
+use std::collections::HashMap;
+
+pub fn build_followers(names: &[&str]) -> HashMap<i32, String> {
+    let mut followers = HashMap::new();
+    for (index, name) in names.iter().enumerate() {
+        followers.insert(index as i32, name.to_string());
+    }
+    followers
+}
+
+pub fn ids_with_prefix(followers: &HashMap<i32, String>, prefix: &str) -> Vec<i32> {
+    let mut ids: Vec<i32> = followers
+        .iter()
+        .filter(|(_, name)| name.starts_with(prefix))
+        .map(|(id, _)| *id)
+        .collect();
+    ids.sort();
+    ids
+}
+
+pub fn unfollow(followers: &mut HashMap<i32, String>, id: i32) -> Option<String> {
+    followers.remove(&id)
+}
@@ -0,0 +1,38 @@
+fn sample() -> HashMap<i32, String> {
+    build_followers(&[
+        "script_saga_follower_one",
+        "script_saga_follower_two",
+        "someone_else",
+    ])
+}
+
+// hint: iterate with names.iter().enumerate() and insert(index as i32, name.to_string())
+#[test]
+fn builds_ids_in_order() {
+    let followers = sample();
+    assert_eq!(followers.len(), 3);
+    assert_eq!(followers[&0], "script_saga_follower_one");
+    assert_eq!(followers[&2], "someone_else");
+}
+
+// hint: an empty slice should give an empty map, HashMap::new() is a good start
+#[test]
+fn builds_an_empty_map() {
+    assert!(build_followers(&[]).is_empty());
+}
+
+// hint: filter over followers.iter() with name.starts_with(prefix), collect the keys and sort them
+#[test]
+fn finds_ids_by_prefix() {
+    assert_eq!(ids_with_prefix(&sample(), "script_saga"), vec![0, 1]);
+    assert_eq!(ids_with_prefix(&sample(), "nobody"), Vec::<i32>::new());
+}
+
+// hint: HashMap::remove already returns an Option with the removed value
+#[test]
+fn unfollow_removes_the_follower() {
+    let mut followers = sample();
+    assert_eq!(unfollow(&mut followers, 1), Some("script_saga_follower_two".to_string()));
+    assert_eq!(unfollow(&mut followers, 1), None);
+    assert!(!followers.contains_key(&1));
+}
@@ -0,0 +1,20 @@
+// Exercise for tut11: file I/O and io::ErrorKind
+//
+// Replace every todo!() below, then run `cargo run -- check tut11_notes`.
+
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, ErrorKind, Write},
+    path::Path,
+};
+
+// The notes in the file, one per line. A missing file means no notes yet, that's not an error,
+// but every other error is given to the caller.
+pub fn read_notes(path: &Path) -> io::Result<Vec<String>> {
+    todo!()
+}
+
+// Adds a note at the end of the file, creating the file when it isn't there yet
+pub fn add_note(path: &Path, note: &str) -> io::Result<()> {
+    todo!()
+}
@@ -0,0 +1,30 @@
+use std::{env, path::PathBuf, process};
+
+fn notes_file(name: &str) -> PathBuf {
+    let path = env::temp_dir().join(format!("practice_one-notes-{}-{}", name, process::id()));
+    fs::remove_file(&path).ok();
+    path
+}
+
+// hint: match fs::read_to_string(path) and return Ok(Vec::new()) when error.kind() == ErrorKind::NotFound
+#[test]
+fn a_missing_file_has_no_notes() {
+    assert_eq!(read_notes(&notes_file("missing")).unwrap(), Vec::<String>::new());
+}
+
+// hint: OpenOptions::new().create(true).append(true).open(path)?, then writeln!(file, "{}", note)
+#[test]
+fn notes_are_appended() {
+    let path = notes_file("append");
+    add_note(&path, "learn lifetimes").unwrap();
+    add_note(&path, "read tut11").unwrap();
+    assert_eq!(read_notes(&path).unwrap(), ["learn lifetimes", "read tut11"]);
+    fs::remove_file(&path).unwrap();
+}
+
+// hint: only NotFound is recovered, reading a directory as a file must still be an error
+#[test]
+fn other_errors_are_returned() {
+    let dir = env::temp_dir();
+    assert!(read_notes(&dir).is_err());
+}
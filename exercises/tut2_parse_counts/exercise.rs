@@ -0,0 +1,20 @@
+// Exercise for tut2: Result, Option and the ? operator
+//
+// Replace every todo!() below, then run `cargo run -- check tut2_parse_counts`.
+
+// Parse a follower count, with the spaces around it trimmed.
+// "42" => Ok(42), " 7 " => Ok(7), "many" => Err("`many` is not a count")
+pub fn parse_count(text: &str) -> Result<u32, String> {
+    todo!()
+}
+
+// Sum of all the counts, the first text that isn't a count is the error.
+// Use parse_count and the ? operator (or collect into a Result).
+pub fn total(texts: &[&str]) -> Result<u32, String> {
+    todo!()
+}
+
+// The first even number of the slice, or None when there isn't one
+pub fn first_even(numbers: &[i32]) -> Option<i32> {
+    todo!()
+}
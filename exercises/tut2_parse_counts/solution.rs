@@ -0,0 +1,19 @@
+// Reference solution for tut2_parse_counts, try the exercise before reading it
+
+pub fn parse_count(text: &str) -> Result<u32, String> {
+    text.trim()
+        .parse::<u32>()
+        .map_err(|_| format!("`{}` is not a count", text))
+}
+
+pub fn total(texts: &[&str]) -> Result<u32, String> {
+    let mut sum = 0;
+    for text in texts {
+        sum += parse_count(text)?;
+    }
+    Ok(sum)
+}
+
+pub fn first_even(numbers: &[i32]) -> Option<i32> {
+    numbers.iter().find(|number| *number % 2 == 0).copied()
+}
@@ -0,0 +1,33 @@
+// hint: text.trim().parse::<u32>() gives a Result, map_err turns its error into the message
+#[test]
+fn parses_counts() {
+    assert_eq!(parse_count("42"), Ok(42));
+    assert_eq!(parse_count(" 7 "), Ok(7));
+}
+
+// hint: format!("`{}` is not a count", text) inside map_err(|_| ...)
+#[test]
+fn reports_bad_counts() {
+    assert_eq!(parse_count("many"), Err("`many` is not a count".to_string()));
+    assert_eq!(parse_count("-1"), Err("`-1` is not a count".to_string()));
+}
+
+// hint: let mut sum = 0; for text in texts { sum += parse_count(text)?; } Ok(sum)
+#[test]
+fn totals_counts() {
+    assert_eq!(total(&["1", "2", "3"]), Ok(6));
+    assert_eq!(total(&[]), Ok(0));
+}
+
+// hint: ? returns the first error right away, the texts after it are never parsed
+#[test]
+fn total_stops_at_the_first_error() {
+    assert_eq!(total(&["1", "x", "y"]), Err("`x` is not a count".to_string()));
+}
+
+// hint: numbers.iter().find(|number| *number % 2 == 0).copied()
+#[test]
+fn finds_the_first_even_number() {
+    assert_eq!(first_even(&[1, 3, 4, 6]), Some(4));
+    assert_eq!(first_even(&[1, 3]), None);
+}
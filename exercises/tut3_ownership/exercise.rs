@@ -0,0 +1,21 @@
+// Exercise for tut3: ownership and borrowing
+//
+// Replace every todo!() below, then run `cargo run -- check tut3_ownership`.
+// Look at the parameter types: who owns the String, and who only borrows it?
+
+// Takes ownership of name and gives back a String with " (follower)" appended:
+// "script" => "script (follower)"
+pub fn into_follower(name: String) -> String {
+    todo!()
+}
+
+// Only borrows the names, so the caller can keep using them: the length of the longest name,
+// 0 for no names
+pub fn longest_len(names: &[String]) -> usize {
+    todo!()
+}
+
+// Borrows the Vec mutably and adds name to it, as an owned String
+pub fn add_follower(names: &mut Vec<String>, name: &str) {
+    todo!()
+}
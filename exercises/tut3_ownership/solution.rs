@@ -0,0 +1,14 @@
+// Reference solution for tut3_ownership, try the exercise before reading it
+
+pub fn into_follower(mut name: String) -> String {
+    name.push_str(" (follower)");
+    name
+}
+
+pub fn longest_len(names: &[String]) -> usize {
+    names.iter().map(|name| name.len()).max().unwrap_or(0)
+}
+
+pub fn add_follower(names: &mut Vec<String>, name: &str) {
+    names.push(name.to_string());
+}
@@ -0,0 +1,24 @@
+// hint: name is yours now, so it can be changed and returned: name.push_str(" (follower)"); name
+#[test]
+fn into_follower_appends() {
+    assert_eq!(into_follower("script".to_string()), "script (follower)");
+}
+
+// hint: names.iter().map(|name| name.len()).max().unwrap_or(0)
+#[test]
+fn longest_len_borrows() {
+    let names = vec!["script".to_string(), "ferris".to_string(), "saga".to_string()];
+    assert_eq!(longest_len(&names), 6);
+    // names is still usable here because it was only borrowed
+    assert_eq!(names.len(), 3);
+    assert_eq!(longest_len(&[]), 0);
+}
+
+// hint: names.push(name.to_string())
+#[test]
+fn add_follower_changes_the_vec() {
+    let mut names = vec!["script".to_string()];
+    add_follower(&mut names, "saga");
+    add_follower(&mut names, "ferris");
+    assert_eq!(names, ["script", "saga", "ferris"]);
+}
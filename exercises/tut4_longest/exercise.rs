@@ -0,0 +1,28 @@
+// Exercise for tut4: lifetimes in functions and structs
+//
+// Replace every todo!() below, the lifetimes are already in place, then run
+// `cargo run -- check tut4_longest`.
+
+// The longer of the two names, the first one when they are as long. The result borrows from
+// both arguments, so both need the same lifetime, like tut4's execute_life_time<'a>.
+pub fn longest<'a>(first: &'a str, second: &'a str) -> &'a str {
+    todo!()
+}
+
+// A follower that borrows its name from a line of text
+pub struct Follower<'a> {
+    pub name: &'a str,
+}
+
+// The text up to the first comma, trimmed, borrowed from line: "script, saga" => "script".
+// A line without a comma is all name.
+pub fn parse_follower(line: &str) -> Follower<'_> {
+    todo!()
+}
+
+impl<'a> Follower<'a> {
+    // The name without copying it, valid as long as the text it was borrowed from
+    pub fn name(&self) -> &'a str {
+        todo!()
+    }
+}
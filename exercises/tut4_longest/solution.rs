@@ -0,0 +1,25 @@
+// Reference solution for tut4_longest, try the exercise before reading it
+
+pub fn longest<'a>(first: &'a str, second: &'a str) -> &'a str {
+    if second.len() > first.len() {
+        second
+    } else {
+        first
+    }
+}
+
+pub struct Follower<'a> {
+    pub name: &'a str,
+}
+
+pub fn parse_follower(line: &str) -> Follower<'_> {
+    Follower {
+        name: line.split(',').next().unwrap_or(line).trim(),
+    }
+}
+
+impl<'a> Follower<'a> {
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+}
@@ -0,0 +1,36 @@
+// hint: if second.len() > first.len() { second } else { first }
+#[test]
+fn picks_the_longest() {
+    assert_eq!(longest("script", "ferris_the_crab"), "ferris_the_crab");
+    assert_eq!(longest("saga", "crab"), "saga");
+}
+
+// hint: the result can only be used while both arguments are alive
+#[test]
+fn result_lives_as_long_as_both_arguments() {
+    let first = String::from("script_saga");
+    let picked;
+    {
+        let second = String::from("saga");
+        picked = longest(&first, &second).to_string();
+    }
+    assert_eq!(picked, "script_saga");
+}
+
+// hint: line.split(',').next().unwrap_or(line).trim()
+#[test]
+fn parses_the_name_before_the_comma() {
+    assert_eq!(parse_follower("script, saga").name, "script");
+    assert_eq!(parse_follower("  ferris  ").name, "ferris");
+}
+
+// hint: return self.name, it already is a &'a str
+#[test]
+fn name_outlives_the_follower() {
+    let line = String::from("grace, hopper");
+    let name = {
+        let follower = parse_follower(&line);
+        follower.name()
+    };
+    assert_eq!(name, "grace");
+}
@@ -0,0 +1,35 @@
+// Exercise for tut5: structs, tuple structs and enums
+//
+// Replace every todo!() below, then run `cargo run -- check tut5_coordinates`.
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CartesianCoordinate(pub i32, pub i32, pub i32);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptSagaFollower {
+    pub first_name: String,
+    pub last_name: String,
+    pub country_code: u16,
+}
+
+pub enum Shape {
+    Square(u32),
+    Rectangle { width: u32, height: u32 },
+}
+
+impl CartesianCoordinate {
+    // The coordinate moved by the other one: (1, 2, 3) + (1, 1, 1) = (2, 3, 4)
+    pub fn moved_by(self, other: CartesianCoordinate) -> CartesianCoordinate {
+        todo!()
+    }
+}
+
+// A new follower with the same names as `follower` but another country code.
+// Use the struct update syntax `..follower`.
+pub fn with_country(follower: ScriptSagaFollower, country_code: u16) -> ScriptSagaFollower {
+    todo!()
+}
+
+pub fn area(shape: &Shape) -> u32 {
+    todo!()
+}
@@ -0,0 +1,36 @@
+// Reference solution for tut5_coordinates, try the exercise before reading it
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CartesianCoordinate(pub i32, pub i32, pub i32);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptSagaFollower {
+    pub first_name: String,
+    pub last_name: String,
+    pub country_code: u16,
+}
+
+pub enum Shape {
+    Square(u32),
+    Rectangle { width: u32, height: u32 },
+}
+
+impl CartesianCoordinate {
+    pub fn moved_by(self, other: CartesianCoordinate) -> CartesianCoordinate {
+        CartesianCoordinate(self.0 + other.0, self.1 + other.1, self.2 + other.2)
+    }
+}
+
+pub fn with_country(follower: ScriptSagaFollower, country_code: u16) -> ScriptSagaFollower {
+    ScriptSagaFollower {
+        country_code,
+        ..follower
+    }
+}
+
+pub fn area(shape: &Shape) -> u32 {
+    match shape {
+        Shape::Square(side) => side * side,
+        Shape::Rectangle { width, height } => width * height,
+    }
+}
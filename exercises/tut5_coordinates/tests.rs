@@ -0,0 +1,27 @@
+// hint: the fields of a tuple struct are self.0, self.1 and self.2
+#[test]
+fn moves_coordinates() {
+    let moved = CartesianCoordinate(1, 2, 3).moved_by(CartesianCoordinate(1, 1, -4));
+    assert_eq!(moved, CartesianCoordinate(2, 3, -1));
+}
+
+// hint: ScriptSagaFollower { country_code, ..follower }
+#[test]
+fn changes_only_the_country() {
+    let follower = ScriptSagaFollower {
+        first_name: "script".to_string(),
+        last_name: "saga".to_string(),
+        country_code: 91,
+    };
+    let moved = with_country(follower.clone(), 44);
+    assert_eq!(moved.country_code, 44);
+    assert_eq!(moved.first_name, follower.first_name);
+    assert_eq!(moved.last_name, follower.last_name);
+}
+
+// hint: match shape { Shape::Square(side) => side * side, Shape::Rectangle { width, height } => ... }
+#[test]
+fn computes_areas() {
+    assert_eq!(area(&Shape::Square(3)), 9);
+    assert_eq!(area(&Shape::Rectangle { width: 2, height: 5 }), 10);
+}
@@ -0,0 +1,20 @@
+// Exercise for tut6: if-else and loops
+//
+// Replace every todo!() below, then run `cargo run -- check tut6_fizzbuzz`.
+
+// "fizz" for multiples of 3, "buzz" for multiples of 5, "fizzbuzz" for both, the number otherwise.
+// Use if-else as an expression.
+pub fn fizzbuzz(number: u32) -> String {
+    todo!()
+}
+
+// fizzbuzz of every number in 1..=last, with a for loop over a range
+pub fn fizzbuzz_up_to(last: u32) -> Vec<String> {
+    todo!()
+}
+
+// How many times can start be doubled before it gets bigger than limit? Use a while loop.
+// doublings(1, 10) == 3 because 1 -> 2 -> 4 -> 8, and 16 would be too big
+pub fn doublings(start: u32, limit: u32) -> u32 {
+    todo!()
+}
@@ -0,0 +1,31 @@
+// Reference solution for tut6_fizzbuzz, try the exercise before reading it
+
+pub fn fizzbuzz(number: u32) -> String {
+    if number % 15 == 0 {
+        "fizzbuzz".to_string()
+    } else if number % 3 == 0 {
+        "fizz".to_string()
+    } else if number % 5 == 0 {
+        "buzz".to_string()
+    } else {
+        number.to_string()
+    }
+}
+
+pub fn fizzbuzz_up_to(last: u32) -> Vec<String> {
+    let mut words = Vec::new();
+    for number in 1..=last {
+        words.push(fizzbuzz(number));
+    }
+    words
+}
+
+pub fn doublings(start: u32, limit: u32) -> u32 {
+    let mut value = start;
+    let mut count = 0;
+    while value * 2 <= limit {
+        value *= 2;
+        count += 1;
+    }
+    count
+}
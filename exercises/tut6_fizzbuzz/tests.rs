@@ -0,0 +1,23 @@
+// hint: check the multiples of 15 first, number % 15 == 0
+#[test]
+fn fizzbuzz_words() {
+    assert_eq!(fizzbuzz(3), "fizz");
+    assert_eq!(fizzbuzz(10), "buzz");
+    assert_eq!(fizzbuzz(30), "fizzbuzz");
+    assert_eq!(fizzbuzz(7), "7");
+}
+
+// hint: 1..=last includes last, 1..last doesn't
+#[test]
+fn fizzbuzz_range() {
+    assert_eq!(fizzbuzz_up_to(5), ["1", "2", "fizz", "4", "buzz"]);
+    assert!(fizzbuzz_up_to(0).is_empty());
+}
+
+// hint: let mut value = start; let mut count = 0; while value * 2 <= limit { ... }
+#[test]
+fn counts_doublings() {
+    assert_eq!(doublings(1, 10), 3);
+    assert_eq!(doublings(5, 5), 0);
+    assert_eq!(doublings(3, 100), 5);
+}
@@ -0,0 +1,27 @@
+// Exercise for tut7: pattern matching on an enum
+//
+// Replace the todo!() below, then run `cargo run -- check tut7_match_enum`.
+
+// The same enum as in tut5
+#[derive(Debug)]
+pub enum ScriptSagaEnum {
+    SimpleVariant,
+    PointStructVariant(i32, i32, i32),
+    StructVariant { first_name: String, last_name: String },
+    SingleArgs(String),
+}
+
+// Describe a value with a match expression:
+// - SimpleVariant                  => "simple"
+// - PointStructVariant(1, 2, 3)    => "point 1,2,3"
+// - StructVariant { "script", "saga" } => "script saga"
+// - SingleArgs("hello")            => "hello"
+pub fn describe(value: &ScriptSagaEnum) -> String {
+    todo!()
+}
+
+// true only for points where all three coordinates are 0, use a pattern instead of comparing
+// the values one by one
+pub fn is_origin(value: &ScriptSagaEnum) -> bool {
+    todo!()
+}
@@ -0,0 +1,25 @@
+// Reference solution for tut7_match_enum, try the exercise before reading it
+
+#[derive(Debug)]
+pub enum ScriptSagaEnum {
+    SimpleVariant,
+    PointStructVariant(i32, i32, i32),
+    StructVariant { first_name: String, last_name: String },
+    SingleArgs(String),
+}
+
+pub fn describe(value: &ScriptSagaEnum) -> String {
+    match value {
+        ScriptSagaEnum::SimpleVariant => "simple".to_string(),
+        ScriptSagaEnum::PointStructVariant(x, y, z) => format!("point {},{},{}", x, y, z),
+        ScriptSagaEnum::StructVariant {
+            first_name,
+            last_name,
+        } => format!("{} {}", first_name, last_name),
+        ScriptSagaEnum::SingleArgs(text) => text.clone(),
+    }
+}
+
+pub fn is_origin(value: &ScriptSagaEnum) -> bool {
+    matches!(value, ScriptSagaEnum::PointStructVariant(0, 0, 0))
+}
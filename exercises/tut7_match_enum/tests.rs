@@ -0,0 +1,35 @@
+// hint: a unit variant is matched with just its path, ScriptSagaEnum::SimpleVariant => ...
+#[test]
+fn describes_simple_variant() {
+    assert_eq!(describe(&ScriptSagaEnum::SimpleVariant), "simple");
+}
+
+// hint: bind the three fields with ScriptSagaEnum::PointStructVariant(x, y, z)
+#[test]
+fn describes_point_variant() {
+    assert_eq!(describe(&ScriptSagaEnum::PointStructVariant(1, 2, 3)), "point 1,2,3");
+}
+
+// hint: struct variants are destructured with curly brackets, StructVariant { first_name, last_name }
+#[test]
+fn describes_struct_variant() {
+    let value = ScriptSagaEnum::StructVariant {
+        first_name: "script".to_string(),
+        last_name: "saga".to_string(),
+    };
+    assert_eq!(describe(&value), "script saga");
+}
+
+// hint: SingleArgs(text) => text.clone()
+#[test]
+fn describes_single_args() {
+    assert_eq!(describe(&ScriptSagaEnum::SingleArgs("hello".to_string())), "hello");
+}
+
+// hint: literals work inside patterns, PointStructVariant(0, 0, 0) => true, _ => false
+#[test]
+fn finds_the_origin() {
+    assert!(is_origin(&ScriptSagaEnum::PointStructVariant(0, 0, 0)));
+    assert!(!is_origin(&ScriptSagaEnum::PointStructVariant(0, 1, 0)));
+    assert!(!is_origin(&ScriptSagaEnum::SimpleVariant));
+}
@@ -0,0 +1,21 @@
+// Exercise for tut8: ranges, match guards and @ bindings
+//
+// Replace every todo!() below, then run `cargo run -- check tut8_classify`.
+
+// Sort a follower count into a tier with range patterns:
+// 0 => "none", 1..=99 => "small", 100..=9_999 => "growing", anything more => "famous"
+pub fn tier(followers: u32) -> &'static str {
+    todo!()
+}
+
+// Describe a point with match guards:
+// (0, 0) => "origin", x == y => "diagonal", x < 0 or y < 0 => "negative", else "positive"
+pub fn describe_point(point: (i32, i32)) -> &'static str {
+    todo!()
+}
+
+// Bind the matched value with @: an age in 13..=19 gives "teen of <age>",
+// anything else "age <age>"
+pub fn age_label(age: u32) -> String {
+    todo!()
+}
@@ -0,0 +1,26 @@
+// Reference solution for tut8_classify, try the exercise before reading it
+
+pub fn tier(followers: u32) -> &'static str {
+    match followers {
+        0 => "none",
+        1..=99 => "small",
+        100..=9_999 => "growing",
+        _ => "famous",
+    }
+}
+
+pub fn describe_point(point: (i32, i32)) -> &'static str {
+    match point {
+        (0, 0) => "origin",
+        (x, y) if x == y => "diagonal",
+        (x, y) if x < 0 || y < 0 => "negative",
+        _ => "positive",
+    }
+}
+
+pub fn age_label(age: u32) -> String {
+    match age {
+        teen @ 13..=19 => format!("teen of {}", teen),
+        other => format!("age {}", other),
+    }
+}
@@ -0,0 +1,24 @@
+// hint: 1..=99 => "small" and a final _ => "famous"
+#[test]
+fn sorts_into_tiers() {
+    assert_eq!(tier(0), "none");
+    assert_eq!(tier(99), "small");
+    assert_eq!(tier(100), "growing");
+    assert_eq!(tier(10_000), "famous");
+}
+
+// hint: (x, y) if x == y => "diagonal", and the order of the arms matters
+#[test]
+fn describes_points() {
+    assert_eq!(describe_point((0, 0)), "origin");
+    assert_eq!(describe_point((-2, -2)), "diagonal");
+    assert_eq!(describe_point((-1, 3)), "negative");
+    assert_eq!(describe_point((4, 1)), "positive");
+}
+
+// hint: teen @ 13..=19 => format!("teen of {}", teen)
+#[test]
+fn labels_ages() {
+    assert_eq!(age_label(15), "teen of 15");
+    assert_eq!(age_label(20), "age 20");
+}
@@ -0,0 +1,33 @@
+// Exercise for tut9: implement a trait for your own structs
+//
+// Replace every todo!() below, then run `cargo run -- check tut9_role`.
+
+pub trait Role {
+    fn get_role(&self) -> String;
+
+    // Optional method: only override it when the default is wrong for your type
+    fn can_publish(&self) -> bool {
+        false
+    }
+}
+
+pub struct Admin;
+
+pub struct Follower {
+    pub name: String,
+}
+
+// An Admin's role is "admin" and admins can publish.
+impl Role for Admin {
+    fn get_role(&self) -> String {
+        todo!()
+    }
+}
+
+// A Follower's role is "follower: <name>", e.g. "follower: script_saga". Followers can't publish,
+// so keep the default can_publish.
+impl Role for Follower {
+    fn get_role(&self) -> String {
+        todo!()
+    }
+}
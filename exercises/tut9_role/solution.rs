@@ -0,0 +1,31 @@
+// Reference solution for tut9_role, try the exercise before reading it
+
+pub trait Role {
+    fn get_role(&self) -> String;
+
+    fn can_publish(&self) -> bool {
+        false
+    }
+}
+
+pub struct Admin;
+
+pub struct Follower {
+    pub name: String,
+}
+
+impl Role for Admin {
+    fn get_role(&self) -> String {
+        "admin".to_string()
+    }
+
+    fn can_publish(&self) -> bool {
+        true
+    }
+}
+
+impl Role for Follower {
+    fn get_role(&self) -> String {
+        format!("follower: {}", self.name)
+    }
+}
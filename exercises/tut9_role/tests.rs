@@ -0,0 +1,42 @@
+// hint: return the text "admin" from Admin::get_role, e.g. "admin".to_string()
+#[test]
+fn admin_role_is_admin() {
+    assert_eq!(Admin.get_role(), "admin");
+}
+
+// hint: override can_publish in `impl Role for Admin` and return true
+#[test]
+fn admin_can_publish() {
+    assert!(Admin.can_publish());
+}
+
+// hint: use format!("follower: {}", self.name)
+#[test]
+fn follower_role_includes_the_name() {
+    let follower = Follower {
+        name: "script_saga".to_string(),
+    };
+    assert_eq!(follower.get_role(), "follower: script_saga");
+}
+
+// hint: don't override can_publish for Follower, the default already returns false
+#[test]
+fn follower_cannot_publish() {
+    let follower = Follower {
+        name: "script_saga".to_string(),
+    };
+    assert!(!follower.can_publish());
+}
+
+// hint: both structs need to implement Role so they can live in one Vec<Box<dyn Role>>
+#[test]
+fn roles_work_as_trait_objects() {
+    let roles: Vec<Box<dyn Role>> = vec![
+        Box::new(Admin),
+        Box::new(Follower {
+            name: "one".to_string(),
+        }),
+    ];
+    let names: Vec<String> = roles.iter().map(|role| role.get_role()).collect();
+    assert_eq!(names, ["admin", "follower: one"]);
+}
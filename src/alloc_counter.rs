@@ -0,0 +1,202 @@
+// Counting heap allocations
+//
+// CountingAllocator wraps the system allocator and counts every allocation, reallocation and free
+// along with the bytes involved, so the claims about what lives on the heap can be checked instead
+// of taken on faith. It only counts once it is installed as the global allocator:
+//
+//     #[global_allocator]
+//     static ALLOCATOR: CountingAllocator = CountingAllocator;
+//
+// The practice_one binary does that when the count-allocations feature is on (it is by default),
+// tests that want to assert allocation counts declare their own.
+//
+// The counters are kept per thread, so measuring a closure only sees what that closure did and not
+// what other threads (e.g. other tests) allocate in the meantime.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt,
+    io::{self, Write},
+    ops::Sub,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub allocations: usize,
+    pub reallocations: usize,
+    pub deallocations: usize,
+    // A reallocation counts its new size as allocated and its old size as freed
+    pub bytes_allocated: usize,
+    pub bytes_freed: usize,
+}
+
+impl Stats {
+    const ZERO: Stats = Stats {
+        allocations: 0,
+        reallocations: 0,
+        deallocations: 0,
+        bytes_allocated: 0,
+        bytes_freed: 0,
+    };
+
+    // Nothing touched the heap
+    pub fn is_empty(&self) -> bool {
+        self.allocations == 0 && self.reallocations == 0 && self.deallocations == 0
+    }
+
+    // Bytes still allocated, negative when more was freed than allocated
+    pub fn live_bytes(&self) -> isize {
+        self.bytes_allocated as isize - self.bytes_freed as isize
+    }
+
+    // "1 allocation, 0 reallocations, 0 frees", without the bytes. How many bytes std asks for can
+    // change between Rust versions, the number of calls to the allocator rarely does.
+    pub fn counts(&self) -> String {
+        format!(
+            "{}, {}, {}",
+            plural(self.allocations, "allocation"),
+            plural(self.reallocations, "reallocation"),
+            plural(self.deallocations, "free")
+        )
+    }
+}
+
+impl Sub for Stats {
+    type Output = Stats;
+
+    fn sub(self, earlier: Stats) -> Stats {
+        Stats {
+            allocations: self.allocations - earlier.allocations,
+            reallocations: self.reallocations - earlier.reallocations,
+            deallocations: self.deallocations - earlier.deallocations,
+            bytes_allocated: self.bytes_allocated - earlier.bytes_allocated,
+            bytes_freed: self.bytes_freed - earlier.bytes_freed,
+        }
+    }
+}
+
+fn plural(count: usize, word: &str) -> String {
+    if count == 1 {
+        format!("{} {}", count, word)
+    } else {
+        format!("{} {}s", count, word)
+    }
+}
+
+// "1 allocation, 0 reallocations, 0 frees, 10 bytes allocated"
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}, {} allocated",
+            self.counts(),
+            plural(self.bytes_allocated, "byte")
+        )
+    }
+}
+
+// A const initialised Cell of a Copy type never allocates, which matters since this is read from
+// inside the allocator
+thread_local! {
+    static STATS: Cell<Stats> = const { Cell::new(Stats::ZERO) };
+}
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+fn update(change: impl FnOnce(&mut Stats)) {
+    INSTALLED.store(true, Ordering::Relaxed);
+    // Fails while the thread is being torn down, those allocations just aren't counted
+    let _ = STATS.try_with(|stats| {
+        let mut current = stats.get();
+        change(&mut current);
+        stats.set(current);
+    });
+}
+
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc(layout);
+        if !pointer.is_null() {
+            update(|stats| {
+                stats.allocations += 1;
+                stats.bytes_allocated += layout.size();
+            });
+        }
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc_zeroed(layout);
+        if !pointer.is_null() {
+            update(|stats| {
+                stats.allocations += 1;
+                stats.bytes_allocated += layout.size();
+            });
+        }
+        pointer
+    }
+
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_pointer = System.realloc(pointer, layout, new_size);
+        if !new_pointer.is_null() {
+            update(|stats| {
+                stats.reallocations += 1;
+                stats.bytes_allocated += new_size;
+                stats.bytes_freed += layout.size();
+            });
+        }
+        new_pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        System.dealloc(pointer, layout);
+        update(|stats| {
+            stats.deallocations += 1;
+            stats.bytes_freed += layout.size();
+        });
+    }
+}
+
+// Whether a CountingAllocator is the global allocator. Anything allocates before main runs, so
+// this is reliable once the program has started.
+pub fn is_installed() -> bool {
+    INSTALLED.load(Ordering::Relaxed)
+}
+
+// First line of the part of a lesson that shows counted allocations, everything the lesson prints
+// after it depends on the counting allocator. tests/snapshots.rs only compares the lines before it
+// when the binary is built without count-allocations.
+pub const COUNTED: &str = "counted allocations:";
+
+// Starts the counted part of a lesson. Without a counting allocator it says so instead and returns
+// false, and the lesson should stop there.
+pub fn counted_section(out: &mut dyn Write) -> io::Result<bool> {
+    if is_installed() {
+        writeln!(out, "{}", COUNTED)?;
+        Ok(true)
+    } else {
+        writeln!(
+            out,
+            "{} off, build with the count-allocations feature to see them",
+            COUNTED
+        )?;
+        Ok(false)
+    }
+}
+
+// Totals of the current thread since it started
+pub fn current() -> Stats {
+    STATS.try_with(Cell::get).unwrap_or_default()
+}
+
+// Runs f and returns what it allocated on this thread along with its result. Return whatever f
+// creates so it isn't dropped (or optimised away) inside the measurement.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Stats) {
+    let before = current();
+    let value = f();
+    (value, current() - before)
+}
@@ -0,0 +1,125 @@
+// Arena allocation
+//
+// An Arena<T> hands out references to values moved into it, and every one of them lives exactly as
+// long as the arena. That's what lifetime-bound structs like tut4::ScriptSagaFollower<'a> want:
+// allocate the names and the followers in arenas, and every &'a in them is the same 'a, the
+// arena's. Values in an arena can point at each other, even in a cycle, because none of them goes
+// away before the others. They're all dropped together with the arena.
+//
+// The values are kept in chunks, each a Vec that's never grown past the capacity it was created
+// with, so a value never moves once it's in the arena. When a chunk is full a new one twice as big
+// is started and the old one is kept as it is. Allocating is pushing onto the current chunk, and
+// the only unsafe code is turning the pushed slot into a reference that outlives the RefCell
+// borrow, which is fine because nothing ever moves or drops that slot before the arena itself.
+
+use std::{cell::RefCell, cmp, mem};
+
+// Capacity of the first chunk, in values
+const FIRST_CHUNK: usize = 8;
+
+struct Chunks<T> {
+    current: Vec<T>,
+    // Full chunks, never touched again until the arena is dropped
+    rest: Vec<Vec<T>>,
+}
+
+impl<T> Chunks<T> {
+    // Starts a new chunk with room for at least `additional` values. The current chunk is moved
+    // into rest, moving a Vec doesn't move the values in its buffer.
+    fn grow(&mut self, additional: usize) {
+        let doubled = self.current.capacity().saturating_mul(2);
+        let capacity = cmp::max(cmp::max(doubled, FIRST_CHUNK), additional);
+        let full = mem::replace(&mut self.current, Vec::with_capacity(capacity));
+        if !full.is_empty() {
+            self.rest.push(full);
+        }
+    }
+}
+
+pub struct Arena<T> {
+    chunks: RefCell<Chunks<T>>,
+}
+
+impl<T> Arena<T> {
+    pub fn new() -> Self {
+        Arena::with_capacity(0)
+    }
+
+    // An arena whose first chunk has room for `capacity` values
+    pub fn with_capacity(capacity: usize) -> Self {
+        Arena {
+            chunks: RefCell::new(Chunks {
+                current: Vec::with_capacity(capacity),
+                rest: Vec::new(),
+            }),
+        }
+    }
+
+    // Moves value into the arena. Every call gives a different slot, so handing out &mut from &self
+    // is fine here.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc(&self, value: T) -> &mut T {
+        let mut chunks = self.chunks.borrow_mut();
+        if chunks.current.len() == chunks.current.capacity() {
+            chunks.grow(1);
+        }
+        let index = chunks.current.len();
+        // There's room, so this push doesn't reallocate and earlier values stay where they are
+        chunks.current.push(value);
+        // SAFETY: the slot at index was just written and is never moved, handed out again or
+        // dropped until the arena is, and the returned reference borrows the arena
+        unsafe { &mut *chunks.current.as_mut_ptr().add(index) }
+    }
+
+    // Moves all the values of an iterator into the arena, next to each other.
+    // The iterator is collected before the arena is touched, so it can allocate in this arena too.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_extend(&self, values: impl IntoIterator<Item = T>) -> &mut [T] {
+        let mut values: Vec<T> = values.into_iter().collect();
+        let mut chunks = self.chunks.borrow_mut();
+        let count = values.len();
+        if chunks.current.capacity() - chunks.current.len() < count {
+            chunks.grow(count);
+        }
+        let start = chunks.current.len();
+        // Enough room for all of them, so again no reallocation
+        chunks.current.append(&mut values);
+        // SAFETY: as in alloc, for the count slots from start that were just written
+        unsafe { std::slice::from_raw_parts_mut(chunks.current.as_mut_ptr().add(start), count) }
+    }
+
+    // Number of values in the arena
+    pub fn len(&self) -> usize {
+        let chunks = self.chunks.borrow();
+        chunks.current.len() + chunks.rest.iter().map(Vec::len).sum::<usize>()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // Number of chunks allocated so far
+    pub fn chunks(&self) -> usize {
+        let chunks = self.chunks.borrow();
+        chunks.rest.len() + usize::from(chunks.current.capacity() > 0)
+    }
+
+    // All the values in the order they were allocated. Taking self means no reference into the
+    // arena is left.
+    pub fn into_vec(self) -> Vec<T> {
+        let Chunks { current, rest } = self.chunks.into_inner();
+        let mut values =
+            Vec::with_capacity(rest.iter().map(Vec::len).sum::<usize>() + current.len());
+        for chunk in rest {
+            values.extend(chunk);
+        }
+        values.extend(current);
+        values
+    }
+}
+
+impl<T> Default for Arena<T> {
+    fn default() -> Self {
+        Arena::new()
+    }
+}
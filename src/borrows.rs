@@ -0,0 +1,1074 @@
+// Borrow timelines
+//
+// Draws where every borrow of a small snippet starts and stops being live, and points out the
+// borrows the borrow checker would reject. Only a small part of Rust is understood, one statement
+// per line:
+//
+//     let [mut] name[: Type] [= expression];
+//     name = expression;   *name = expression;   expression;
+//     { ... }   and   fn name(arg: Type, ...) { ... }
+//
+// where an expression is a variable, a literal, `&x`, `&mut x`, `*r`, a call `f(...)` or `m!(...)`,
+// a method call `x.f(...)`, a field `x.y`, `a + b` or `x?`.
+//
+// The analysis is a simple version of the non-lexical lifetimes the compiler uses: a borrow is live
+// from the line it is created until the last line that uses a variable holding it, not until the
+// end of the block. Variables holding a reference pass their borrows on when they're copied, and
+// like lifetime elision the result of a function call is assumed to borrow from its arguments.
+// Without types, a variable initialised from a literal, `*r` or arithmetic is taken to be Copy and
+// anything else is moved when given by value to a function or another variable, after which using
+// it is an error until it is assigned again. A variable or parameter with a primitive type like
+// i32 or bool is Copy as well. Whether a method takes &self or &mut self isn't known either, so a
+// method call on a variable declared `mut` is taken to borrow it mutably, like `v.push(x)` does,
+// and on any other variable to only read it.
+
+use crate::error::{Error, Result};
+use std::fmt::{self, Write as _};
+
+const LABELS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+// Types that are copied instead of moved
+const COPY_TYPES: &[&str] = &[
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize", "f32",
+    "f64", "bool", "char",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    // Names, paths like String::from and macros like println!
+    Ident(String),
+    Literal,
+    Lifetime,
+    Punct(char),
+}
+
+// The tokens of a line, each with its 1-based column
+fn tokenize(line: &str, number: usize) -> Result<Vec<(Token, usize)>> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    let error = |column: usize, message: String| Error::Parse {
+        line: number,
+        column: Some(column),
+        message,
+    };
+
+    while i < chars.len() {
+        let c = chars[i];
+        let column = i + 1;
+        let token = if c.is_whitespace() {
+            i += 1;
+            continue;
+        } else if c == '/' && chars.get(i + 1) == Some(&'/') {
+            break;
+        } else if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() {
+                if chars[i].is_alphanumeric() || chars[i] == '_' {
+                    i += 1;
+                } else if chars[i] == ':'
+                    && chars.get(i + 1) == Some(&':')
+                    && chars
+                        .get(i + 2)
+                        .is_some_and(|c| c.is_alphabetic() || *c == '_')
+                {
+                    i += 2;
+                } else {
+                    break;
+                }
+            }
+            if chars.get(i) == Some(&'!') && chars.get(i + 1) == Some(&'(') {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            match word.as_str() {
+                "true" | "false" => Token::Literal,
+                _ => Token::Ident(word),
+            }
+        } else if c.is_ascii_digit() {
+            while i < chars.len()
+                && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '.')
+            {
+                i += 1;
+            }
+            Token::Literal
+        } else if c == '"' {
+            i += 1;
+            while i < chars.len() && chars[i] != '"' {
+                i += if chars[i] == '\\' { 2 } else { 1 };
+            }
+            if i >= chars.len() {
+                return Err(error(column, "unterminated string".to_string()));
+            }
+            i += 1;
+            Token::Literal
+        } else if c == '\'' && chars.get(i + 1) == Some(&'\\') {
+            // An escaped char like '\n' or '\u{1F980}'. The escaped char itself can be a quote, as
+            // in '\'', so the closing quote is searched for after it.
+            let end = chars
+                .get(i + 3..)
+                .and_then(|rest| rest.iter().position(|c| *c == '\''));
+            match end {
+                Some(end) => i += 3 + end + 1,
+                None => return Err(error(column, "unterminated char".to_string())),
+            }
+            Token::Literal
+        } else if c == '\'' {
+            // 'x' is a char, 'a without a closing quote is a lifetime
+            if chars.get(i + 2) == Some(&'\'') {
+                i += 3;
+                Token::Literal
+            } else {
+                i += 1;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                Token::Lifetime
+            }
+        } else if "&*(){},;=.+-/<>:!?".contains(c) {
+            i += 1;
+            Token::Punct(c)
+        } else {
+            return Err(error(
+                column,
+                format!("`{}` isn't part of the snippets understood here", c),
+            ));
+        };
+        tokens.push((token, column));
+    }
+    Ok(tokens)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    // Literals, and `*r` or arithmetic, give Copy values
+    Copy,
+    Var(String),
+    Borrow {
+        mutable: bool,
+        place: String,
+    },
+    Deref(Box<Expr>),
+    Field(Box<Expr>),
+    Call {
+        macro_call: bool,
+        args: Vec<Expr>,
+    },
+    Method {
+        receiver: Box<Expr>,
+        args: Vec<Expr>,
+    },
+    Binary(Box<Expr>, Box<Expr>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Param {
+    name: String,
+    mutable: bool,
+    reference: bool,
+    copy: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Statement {
+    Empty,
+    Open(Vec<Param>),
+    Close,
+    Let {
+        name: String,
+        mutable: bool,
+        reference_type: bool,
+        copy_type: bool,
+        value: Option<Expr>,
+    },
+    Assign {
+        name: String,
+        through_deref: bool,
+        value: Expr,
+    },
+    Expr(Expr),
+}
+
+struct Parser<'t> {
+    tokens: &'t [Token],
+    // 1-based column of every token, and the column just past the end of the line
+    columns: &'t [usize],
+    end_column: usize,
+    position: usize,
+    line: usize,
+}
+
+impl Parser<'_> {
+    // An error at the token that is up next
+    fn error(&self, message: impl Into<String>) -> Error {
+        self.error_at(self.position, message)
+    }
+
+    // An error at the token that was just taken with next()
+    fn error_at_previous(&self, message: impl Into<String>) -> Error {
+        self.error_at(self.position.saturating_sub(1), message)
+    }
+
+    fn error_at(&self, position: usize, message: impl Into<String>) -> Error {
+        Error::Parse {
+            line: self.line,
+            column: Some(
+                self.columns
+                    .get(position)
+                    .copied()
+                    .unwrap_or(self.end_column),
+            ),
+            message: message.into(),
+        }
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn eat(&mut self, punct: char) -> bool {
+        if self.peek() == Some(&Token::Punct(punct)) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_word(&mut self, word: &str) -> bool {
+        if matches!(self.peek(), Some(Token::Ident(ident)) if ident == word) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, punct: char) -> Result<()> {
+        if self.eat(punct) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected `{}`", punct)))
+        }
+    }
+
+    fn name(&mut self) -> Result<String> {
+        match self.next() {
+            Some(Token::Ident(name)) if !name.contains([':', '!']) => Ok(name),
+            _ => Err(self.error_at_previous("expected a variable name")),
+        }
+    }
+
+    fn at_end(&self) -> bool {
+        self.position >= self.tokens.len()
+    }
+
+    // Skips a type up to one of the stop characters outside brackets, returns whether it's a
+    // reference and whether it's a Copy primitive. A bracket that is never closed would swallow
+    // the rest of the line, so it's an error.
+    fn skip_type(&mut self, stops: &[char]) -> Result<(bool, bool)> {
+        let start = self.position;
+        let reference = self.peek() == Some(&Token::Punct('&'));
+        // The brackets still open and where they are
+        let mut open = Vec::new();
+        while let Some(token) = self.peek() {
+            match token {
+                Token::Punct(c) if open.is_empty() && stops.contains(c) => break,
+                Token::Punct(bracket @ ('<' | '(')) => open.push((*bracket, self.position)),
+                // The > of -> doesn't close anything
+                Token::Punct('>')
+                    if self.position > start
+                        && self.tokens[self.position - 1] == Token::Punct('-') => {}
+                Token::Punct(closing @ ('>' | ')')) => match open.pop() {
+                    Some(('<', _)) if *closing == '>' => {}
+                    Some(('(', _)) if *closing == ')' => {}
+                    Some((bracket, position)) => {
+                        return Err(
+                            self.error_at(position, format!("`{}` is never closed", bracket))
+                        )
+                    }
+                    None => {}
+                },
+                _ => {}
+            }
+            self.position += 1;
+        }
+        if let Some(&(bracket, position)) = open.last() {
+            return Err(self.error_at(position, format!("`{}` is never closed", bracket)));
+        }
+        let copy = matches!(
+            &self.tokens[start..self.position],
+            [Token::Ident(name)] if COPY_TYPES.contains(&name.as_str())
+        );
+        Ok((reference, copy))
+    }
+
+    fn expression(&mut self) -> Result<Expr> {
+        let mut left = self.unary()?;
+        while let Some(Token::Punct('+' | '-' | '*' | '/')) = self.peek() {
+            self.position += 1;
+            let right = self.unary()?;
+            left = Expr::Binary(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Result<Expr> {
+        if self.eat('&') {
+            let mutable = self.eat_word("mut");
+            return match self.unary()? {
+                Expr::Var(place) => Ok(Expr::Borrow { mutable, place }),
+                // Borrowing a field borrows the variable it belongs to
+                Expr::Field(base) => match *base {
+                    Expr::Var(place) => Ok(Expr::Borrow { mutable, place }),
+                    _ => Err(self.error("only variables and their fields can be borrowed here")),
+                },
+                Expr::Copy => Ok(Expr::Copy),
+                _ => Err(self.error("only variables and their fields can be borrowed here")),
+            };
+        }
+        if self.eat('*') {
+            return Ok(Expr::Deref(Box::new(self.unary()?)));
+        }
+        if self.eat('-') || self.eat('!') {
+            return Ok(Expr::Binary(Box::new(self.unary()?), Box::new(Expr::Copy)));
+        }
+        self.postfix()
+    }
+
+    fn postfix(&mut self) -> Result<Expr> {
+        let mut expr = self.primary()?;
+        loop {
+            // `?` only passes errors on, for the borrows the value stays the same
+            if self.eat('?') {
+                continue;
+            }
+            if !self.eat('.') {
+                break;
+            }
+            match self.next() {
+                Some(Token::Ident(_)) if self.eat('(') => {
+                    let args = self.arguments()?;
+                    expr = Expr::Method {
+                        receiver: Box::new(expr),
+                        args,
+                    };
+                }
+                Some(Token::Ident(_)) | Some(Token::Literal) => expr = Expr::Field(Box::new(expr)),
+                _ => return Err(self.error_at_previous("expected a field or method after `.`")),
+            }
+        }
+        Ok(expr)
+    }
+
+    fn primary(&mut self) -> Result<Expr> {
+        match self.next() {
+            Some(Token::Literal) => Ok(Expr::Copy),
+            Some(Token::Ident(name)) if self.eat('(') => Ok(Expr::Call {
+                macro_call: name.ends_with('!'),
+                args: self.arguments()?,
+            }),
+            Some(Token::Ident(name)) if name.contains("::") => Ok(Expr::Copy),
+            Some(Token::Ident(name)) => Ok(Expr::Var(name)),
+            Some(Token::Punct('(')) => {
+                let expr = self.expression()?;
+                self.expect(')')?;
+                Ok(expr)
+            }
+            _ => Err(self.error_at_previous("expected an expression")),
+        }
+    }
+
+    // After the opening parenthesis, up to and including the closing one
+    fn arguments(&mut self) -> Result<Vec<Expr>> {
+        let mut args = Vec::new();
+        while !self.eat(')') {
+            args.push(self.expression()?);
+            if !self.eat(',') && self.peek() != Some(&Token::Punct(')')) {
+                return Err(self.error("expected `,` or `)` between arguments"));
+            }
+        }
+        Ok(args)
+    }
+
+    // `fn name<'a>(arg: Type, mut other: &Type) -> Type {`
+    fn function_header(&mut self) -> Result<Vec<Param>> {
+        self.eat_word("pub");
+        if !self.eat_word("fn") {
+            return Err(self.error("only `{` and function headers can open a block"));
+        }
+        self.name()?;
+        if self.eat('<') {
+            self.skip_type(&['>'])?;
+            self.expect('>')?;
+        }
+        self.expect('(')?;
+        let mut params = Vec::new();
+        while !self.eat(')') {
+            let mutable = self.eat_word("mut");
+            let name = self.name()?;
+            self.expect(':')?;
+            let (reference, copy) = self.skip_type(&[',', ')'])?;
+            params.push(Param {
+                name,
+                mutable,
+                reference,
+                copy,
+            });
+            self.eat(',');
+        }
+        if self.eat('-') {
+            self.expect('>')?;
+            self.skip_type(&['{'])?;
+        }
+        Ok(params)
+    }
+
+    fn statement(&mut self) -> Result<Statement> {
+        let statement = match self.tokens {
+            [] => return Ok(Statement::Empty),
+            [Token::Punct('}')] | [Token::Punct('}'), Token::Punct(';')] => {
+                return Ok(Statement::Close)
+            }
+            [.., Token::Punct('{')] => {
+                let params = if self.tokens.len() == 1 {
+                    Vec::new()
+                } else {
+                    self.function_header()?
+                };
+                self.expect('{')?;
+                Statement::Open(params)
+            }
+            [Token::Ident(word), ..] if word == "let" => {
+                self.position += 1;
+                let mutable = self.eat_word("mut");
+                let name = self.name()?;
+                let (reference_type, copy_type) = if self.eat(':') {
+                    self.skip_type(&['=', ';'])?
+                } else {
+                    (false, false)
+                };
+                let value = if self.eat('=') {
+                    Some(self.expression()?)
+                } else {
+                    None
+                };
+                Statement::Let {
+                    name,
+                    mutable,
+                    reference_type,
+                    copy_type,
+                    value,
+                }
+            }
+            [Token::Punct('*'), Token::Ident(name), Token::Punct('='), ..]
+            | [Token::Ident(name), Token::Punct('='), ..]
+                if !name.contains([':', '!']) =>
+            {
+                let through_deref = self.tokens[0] == Token::Punct('*');
+                let name = name.clone();
+                self.position = if through_deref { 3 } else { 2 };
+                Statement::Assign {
+                    name,
+                    through_deref,
+                    value: self.expression()?,
+                }
+            }
+            _ => Statement::Expr(self.expression()?),
+        };
+        self.eat(';');
+        if !self.at_end() {
+            return Err(self.error("expected one statement per line"));
+        }
+        Ok(statement)
+    }
+}
+
+// A borrow, and the lines it is live on
+#[derive(Debug, Clone, PartialEq)]
+pub struct Loan {
+    pub label: char,
+    pub place: String,
+    pub mutable: bool,
+    pub created: usize,
+    // The last line it is used on, the same as created for a temporary
+    pub end: usize,
+    // The variables that held it, empty when it was only passed to a call
+    pub holders: Vec<String>,
+    // Lines after created that use it
+    pub uses: Vec<usize>,
+    place_var: usize,
+    holder_vars: Vec<usize>,
+    event: usize,
+}
+
+impl Loan {
+    // "first = &arg1" or "temporary &mut arg1"
+    pub fn describe(&self) -> String {
+        let borrow = format!("&{}{}", if self.mutable { "mut " } else { "" }, self.place);
+        if self.holders.is_empty() {
+            format!("temporary {}", borrow)
+        } else {
+            format!("{} = {}", self.holders.join(" / "), borrow)
+        }
+    }
+
+    pub fn is_live_on(&self, line: usize) -> bool {
+        self.created <= line && line <= self.end
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conflict {
+    pub line: usize,
+    // The code rustc reports, e.g. "E0502"
+    pub code: &'static str,
+    pub message: String,
+    // Index in Analysis::loans of the live borrow that is in the way, if any
+    pub loan: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Access {
+    Borrow { mutable: bool },
+    // Borrowing for a moment, like println! or a method call on a variable that isn't mut does
+    Read,
+    // Copying the value, like arithmetic does
+    Use,
+    Write,
+    Move,
+    ScopeEnd,
+}
+
+#[derive(Debug, Clone)]
+struct Event {
+    line: usize,
+    var: usize,
+    access: Access,
+}
+
+#[derive(Debug, Clone)]
+struct Var {
+    name: String,
+    mutable: bool,
+    reference: bool,
+    copy: bool,
+    holds: Vec<usize>,
+    uses: Vec<usize>,
+}
+
+#[derive(Default)]
+struct Analyser {
+    vars: Vec<Var>,
+    scopes: Vec<Vec<usize>>,
+    loans: Vec<Loan>,
+    events: Vec<Event>,
+    conflicts: Vec<Conflict>,
+    line: usize,
+    // The code on that line, to point errors at the right column
+    text: String,
+}
+
+// 1-based column of the first `word` on a line that isn't part of a longer name
+fn column_of(text: &str, word: &str) -> Option<usize> {
+    let is_name = |c: char| c.is_alphanumeric() || c == '_';
+    text.match_indices(word)
+        .find(|(start, _)| {
+            !text[..*start].chars().next_back().is_some_and(is_name)
+                && !text[start + word.len()..]
+                    .chars()
+                    .next()
+                    .is_some_and(is_name)
+        })
+        .map(|(start, _)| text[..start].chars().count() + 1)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Context {
+    // Given by value, a move unless the value is Copy
+    Value,
+    Read,
+    Use,
+    // The receiver of a method call, which may take &mut self
+    Receiver,
+}
+
+impl Analyser {
+    fn lookup(&self, name: &str) -> Result<usize> {
+        self.scopes
+            .iter()
+            .rev()
+            .flatten()
+            .rev()
+            .copied()
+            .find(|var| self.vars[*var].name == name)
+            .ok_or_else(|| Error::Parse {
+                line: self.line,
+                column: column_of(&self.text, name),
+                message: format!("`{}` isn't declared", name),
+            })
+    }
+
+    fn declare(&mut self, var: Var) -> usize {
+        self.vars.push(var);
+        let index = self.vars.len() - 1;
+        self.scopes
+            .last_mut()
+            .expect("the snippet scope is never closed")
+            .push(index);
+        index
+    }
+
+    fn event(&mut self, var: usize, access: Access) {
+        self.events.push(Event {
+            line: self.line,
+            var,
+            access,
+        });
+    }
+
+    fn use_reference(&mut self, var: usize) -> Vec<usize> {
+        self.vars[var].uses.push(self.line);
+        self.vars[var].holds.clone()
+    }
+
+    // Records what evaluating expr does, returns the borrows that end up in its value
+    fn walk(&mut self, expr: &Expr, context: Context) -> Result<Vec<usize>> {
+        Ok(match expr {
+            Expr::Copy => Vec::new(),
+            Expr::Var(name) => {
+                let var = self.lookup(name)?;
+                if self.vars[var].reference {
+                    self.use_reference(var)
+                } else {
+                    let access = match context {
+                        Context::Value if !self.vars[var].copy => Access::Move,
+                        Context::Receiver if self.vars[var].mutable => {
+                            Access::Borrow { mutable: true }
+                        }
+                        Context::Read | Context::Receiver => Access::Read,
+                        _ => Access::Use,
+                    };
+                    self.event(var, access);
+                    Vec::new()
+                }
+            }
+            Expr::Borrow { mutable, place } => {
+                let var = self.lookup(place)?;
+                // Reborrowing through a reference keeps the original borrows alive as well
+                let mut loans = if self.vars[var].reference {
+                    self.use_reference(var)
+                } else {
+                    Vec::new()
+                };
+                if *mutable && !self.vars[var].mutable && !self.vars[var].reference {
+                    self.conflicts.push(Conflict {
+                        line: self.line,
+                        code: "E0596",
+                        message: format!(
+                            "cannot borrow `{}` as mutable, as it is not declared as mutable",
+                            place
+                        ),
+                        loan: None,
+                    });
+                }
+                self.loans.push(Loan {
+                    label: LABELS.chars().nth(self.loans.len()).unwrap_or('#'),
+                    place: place.clone(),
+                    mutable: *mutable,
+                    created: self.line,
+                    end: self.line,
+                    holders: Vec::new(),
+                    uses: Vec::new(),
+                    place_var: var,
+                    holder_vars: Vec::new(),
+                    event: self.events.len(),
+                });
+                self.event(var, Access::Borrow { mutable: *mutable });
+                loans.push(self.loans.len() - 1);
+                loans
+            }
+            Expr::Deref(inner) => {
+                self.walk(inner, Context::Use)?;
+                Vec::new()
+            }
+            Expr::Field(inner) => self.walk(inner, context)?,
+            Expr::Call { macro_call, args } => {
+                let mut loans = Vec::new();
+                for arg in args {
+                    // Macros like println! only borrow their arguments
+                    let context = if *macro_call {
+                        Context::Read
+                    } else {
+                        Context::Value
+                    };
+                    loans.extend(self.walk(arg, context)?);
+                }
+                if *macro_call {
+                    Vec::new()
+                } else {
+                    loans
+                }
+            }
+            Expr::Method { receiver, args } => {
+                let mut loans = self.walk(receiver, Context::Receiver)?;
+                for arg in args {
+                    loans.extend(self.walk(arg, Context::Value)?);
+                }
+                loans
+            }
+            Expr::Binary(left, right) => {
+                self.walk(left, Context::Use)?;
+                self.walk(right, Context::Use)?;
+                Vec::new()
+            }
+        })
+    }
+
+    fn hold(&mut self, var: usize, loans: Vec<usize>) {
+        for loan in &loans {
+            if !self.loans[*loan].holder_vars.contains(&var) {
+                self.loans[*loan].holder_vars.push(var);
+                let name = self.vars[var].name.clone();
+                self.loans[*loan].holders.push(name);
+            }
+        }
+        if !loans.is_empty() {
+            self.vars[var].reference = true;
+        }
+        self.vars[var].holds = loans;
+    }
+
+    fn statement(&mut self, statement: &Statement) -> Result<()> {
+        match statement {
+            Statement::Empty => {}
+            Statement::Open(params) => {
+                self.scopes.push(Vec::new());
+                for param in params {
+                    self.declare(Var {
+                        name: param.name.clone(),
+                        mutable: param.mutable,
+                        reference: param.reference,
+                        copy: param.copy,
+                        holds: Vec::new(),
+                        uses: Vec::new(),
+                    });
+                }
+            }
+            Statement::Close => {
+                if self.scopes.len() == 1 {
+                    return Err(Error::Parse {
+                        line: self.line,
+                        column: column_of(&self.text, "}"),
+                        message: "`}` without a matching `{`".to_string(),
+                    });
+                }
+                let scope = self.scopes.pop().expect("checked above");
+                for var in scope.into_iter().rev() {
+                    self.event(var, Access::ScopeEnd);
+                }
+            }
+            Statement::Let {
+                name,
+                mutable,
+                reference_type,
+                copy_type,
+                value,
+            } => {
+                // The value is evaluated before the new variable shadows an old one
+                let loans = match value {
+                    Some(value) => self.walk(value, Context::Value)?,
+                    None => Vec::new(),
+                };
+                let copy = *copy_type
+                    || match value {
+                        Some(Expr::Var(other)) => self.vars[self.lookup(other)?].copy,
+                        Some(value) => matches!(
+                            value,
+                            Expr::Copy | Expr::Deref(_) | Expr::Binary(..) | Expr::Borrow { .. }
+                        ),
+                        None => false,
+                    };
+                let var = self.declare(Var {
+                    name: name.clone(),
+                    mutable: *mutable,
+                    reference: *reference_type,
+                    copy,
+                    holds: Vec::new(),
+                    uses: Vec::new(),
+                });
+                self.hold(var, loans);
+            }
+            Statement::Assign {
+                name,
+                through_deref,
+                value,
+            } => {
+                let loans = self.walk(value, Context::Value)?;
+                let var = self.lookup(name)?;
+                if *through_deref {
+                    self.use_reference(var);
+                } else if self.vars[var].reference || !loans.is_empty() {
+                    // Pointing a reference somewhere else, what it held before doesn't matter now
+                    self.hold(var, loans);
+                } else {
+                    self.event(var, Access::Write);
+                }
+            }
+            Statement::Expr(expr) => {
+                self.walk(expr, Context::Value)?;
+            }
+        }
+        Ok(())
+    }
+
+    // A borrow lasts until the last use of any variable holding it
+    fn compute_ends(&mut self) {
+        for loan in &mut self.loans {
+            let mut uses: Vec<usize> = loan
+                .holder_vars
+                .iter()
+                .flat_map(|var| self.vars[*var].uses.iter().copied())
+                .filter(|line| *line > loan.created)
+                .collect();
+            uses.sort_unstable();
+            uses.dedup();
+            loan.end = uses.last().copied().unwrap_or(loan.created);
+            loan.uses = uses;
+        }
+    }
+
+    fn check(&mut self) {
+        for (index, event) in self.events.iter().enumerate() {
+            let blocking = self.loans.iter().position(|loan| {
+                loan.place_var == event.var
+                    && loan.event < index
+                    && event.line <= loan.end
+                    && match event.access {
+                        Access::Borrow { mutable: true } | Access::Write | Access::Move => true,
+                        Access::ScopeEnd => event.line < loan.end,
+                        Access::Borrow { mutable: false } | Access::Read | Access::Use => {
+                            loan.mutable
+                        }
+                    }
+            });
+            let Some(loan) = blocking else {
+                continue;
+            };
+
+            let place = &self.vars[event.var].name;
+            let (code, message) = match event.access {
+                Access::Borrow { mutable: true } if self.loans[loan].mutable => (
+                    "E0499",
+                    format!(
+                        "cannot borrow `{}` as mutable more than once at a time",
+                        place
+                    ),
+                ),
+                Access::Borrow { mutable: true } => (
+                    "E0502",
+                    format!(
+                        "cannot borrow `{}` as mutable because it is also borrowed as immutable",
+                        place
+                    ),
+                ),
+                Access::Borrow { mutable: false } | Access::Read => (
+                    "E0502",
+                    format!(
+                        "cannot borrow `{}` as immutable because it is also borrowed as mutable",
+                        place
+                    ),
+                ),
+                Access::Use => (
+                    "E0503",
+                    format!("cannot use `{}` because it was mutably borrowed", place),
+                ),
+                Access::Write => (
+                    "E0506",
+                    format!("cannot assign to `{}` because it is borrowed", place),
+                ),
+                Access::Move => (
+                    "E0505",
+                    format!("cannot move out of `{}` because it is borrowed", place),
+                ),
+                Access::ScopeEnd => ("E0597", format!("`{}` does not live long enough", place)),
+            };
+            self.conflicts.push(Conflict {
+                line: event.line,
+                code,
+                message,
+                loan: Some(loan),
+            });
+        }
+
+        // A moved variable can't be used until it is assigned again
+        let mut moved_on: Vec<Option<usize>> = vec![None; self.vars.len()];
+        for event in &self.events {
+            if let Some(moved_line) = moved_on[event.var] {
+                let verb = match event.access {
+                    Access::Write => {
+                        moved_on[event.var] = None;
+                        continue;
+                    }
+                    Access::ScopeEnd => continue,
+                    Access::Borrow { .. } | Access::Read => "borrow",
+                    Access::Use | Access::Move => "use",
+                };
+                self.conflicts.push(Conflict {
+                    line: event.line,
+                    code: "E0382",
+                    message: format!(
+                        "{} of moved value: `{}`, it was moved on line {}",
+                        verb, self.vars[event.var].name, moved_line
+                    ),
+                    loan: None,
+                });
+            }
+            // Errors point at the first move, like rustc does
+            if event.access == Access::Move && moved_on[event.var].is_none() {
+                moved_on[event.var] = Some(event.line);
+            }
+        }
+        self.conflicts.sort_by_key(|conflict| conflict.line);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Analysis {
+    pub lines: Vec<String>,
+    pub loans: Vec<Loan>,
+    pub conflicts: Vec<Conflict>,
+}
+
+impl Analysis {
+    // Whether the borrow checker would accept the snippet, as far as this analysis can tell
+    pub fn is_accepted(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+
+    pub fn loans_live_on(&self, line: usize) -> impl Iterator<Item = &Loan> {
+        self.loans.iter().filter(move |loan| loan.is_live_on(line))
+    }
+}
+
+pub fn analyse(source: &str) -> Result<Analysis> {
+    let mut analyser = Analyser {
+        scopes: vec![Vec::new()],
+        ..Analyser::default()
+    };
+    let lines: Vec<String> = source.lines().map(str::to_string).collect();
+
+    for (index, text) in lines.iter().enumerate() {
+        analyser.line = index + 1;
+        analyser.text = text.clone();
+        let (tokens, columns): (Vec<Token>, Vec<usize>) =
+            tokenize(text, analyser.line)?.into_iter().unzip();
+        let statement = Parser {
+            tokens: &tokens,
+            columns: &columns,
+            end_column: text.chars().count() + 1,
+            position: 0,
+            line: analyser.line,
+        }
+        .statement()?;
+        analyser.statement(&statement)?;
+    }
+    if analyser.scopes.len() > 1 {
+        return Err(Error::Parse {
+            line: lines.len(),
+            column: None,
+            message: "`{` without a matching `}`".to_string(),
+        });
+    }
+
+    analyser.compute_ends();
+    analyser.check();
+    Ok(Analysis {
+        lines,
+        loans: analyser.loans,
+        conflicts: analyser.conflicts,
+    })
+}
+
+// One column per borrow next to the code:
+//
+//     o  the borrow is created
+//     |  the borrow is live
+//     *  the borrow is used
+impl fmt::Display for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.lines.len().to_string().len();
+        let labels: Vec<String> = self
+            .loans
+            .iter()
+            .map(|loan| loan.label.to_string())
+            .collect();
+        writeln!(f, "{:>width$}   {}", "", labels.join(" "), width = width)?;
+
+        for (index, text) in self.lines.iter().enumerate() {
+            let line = index + 1;
+            let mut cells = String::new();
+            for loan in &self.loans {
+                let cell = if line == loan.created {
+                    'o'
+                } else if loan.uses.contains(&line) {
+                    '*'
+                } else if loan.is_live_on(line) {
+                    '|'
+                } else {
+                    ' '
+                };
+                write!(cells, "{} ", cell)?;
+            }
+            let mut code = text.trim_end().to_string();
+            for conflict in self
+                .conflicts
+                .iter()
+                .filter(|conflict| conflict.line == line)
+            {
+                write!(code, "  // error[{}]", conflict.code)?;
+            }
+            let row = format!("{:>width$} | {}| {}", line, cells, code, width = width);
+            writeln!(f, "{}", row.trim_end())?;
+        }
+
+        writeln!(f)?;
+        for loan in &self.loans {
+            let lines = if loan.end == loan.created {
+                format!("line {}", loan.created)
+            } else {
+                format!("lines {}-{}", loan.created, loan.end)
+            };
+            writeln!(f, "{}  {:<40}  {}", loan.label, loan.describe(), lines)?;
+        }
+        if self.loans.is_empty() {
+            writeln!(f, "no borrows")?;
+        }
+
+        writeln!(f)?;
+        for conflict in &self.conflicts {
+            writeln!(
+                f,
+                "error[{}] on line {}: {}",
+                conflict.code, conflict.line, conflict.message
+            )?;
+            if let Some(loan) = conflict.loan.map(|loan| &self.loans[loan]) {
+                writeln!(
+                    f,
+                    "    {} borrows `{}` on line {} and is used until line {}",
+                    loan.label, loan.place, loan.created, loan.end
+                )?;
+            }
+        }
+        if self.is_accepted() {
+            writeln!(f, "no conflicts, these borrows follow the rules")?;
+        }
+        Ok(())
+    }
+}
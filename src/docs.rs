@@ -0,0 +1,155 @@
+// Markdown export of the tutorials
+//
+// One chapter per tutorial is generated from the source files: every top-level comment becomes
+// text, the code it explains becomes a rust code block and the diagrams of the lessons defined in
+// that code are embedded right after it. README.md links all the chapters together.
+
+use crate::{
+    registry::{Lesson, Registry},
+    source::{self, Segment},
+};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+pub fn images_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("images")
+}
+
+// Copies images/ next to the generated files so the output can be moved around as a whole
+pub fn copy_images(out_dir: &Path) -> io::Result<()> {
+    let target = out_dir.join("images");
+    fs::create_dir_all(&target)?;
+    for entry in fs::read_dir(images_dir())? {
+        let path = entry?.path();
+        if let Some(name) = path.file_name() {
+            fs::copy(&path, target.join(name))?;
+        }
+    }
+    Ok(())
+}
+
+// Spaces are fine in file names but not in Markdown links
+pub fn image_link(diagram: &str) -> String {
+    format!("images/{}", diagram.replace(' ', "%20"))
+}
+
+pub fn chapter_file(tutorial: u8) -> String {
+    format!("tut{}.md", tutorial)
+}
+
+// A comment that starts with a single short line reads like a heading, e.g.
+// "// Understanding Rust Hash Maps"
+fn split_heading(comment: &[String]) -> (Option<&str>, &[String]) {
+    match comment {
+        [first, rest @ ..]
+            if first.len() <= 80 && rest.first().is_none_or(|line| line.is_empty()) =>
+        {
+            (Some(first.as_str()), rest)
+        }
+        _ => (None, comment),
+    }
+}
+
+fn segment_markdown(tutorial: u8, segment: &Segment, lessons: &[&dyn Lesson]) -> String {
+    let mut markdown = String::new();
+
+    let (heading, text) = split_heading(&segment.comment);
+    if let Some(heading) = heading {
+        markdown.push_str(&format!("## {}\n\n", heading.trim_end_matches('.')));
+    }
+    let text = text.join("\n");
+    if !text.trim().is_empty() {
+        markdown.push_str(text.trim());
+        markdown.push_str("\n\n");
+    }
+
+    if !segment.code.is_empty() {
+        markdown.push_str(&format!(
+            "```rust\n{}\n```\n\n_src/lessons/tut{}.rs, line {}_\n\n",
+            segment.code.join("\n"),
+            tutorial,
+            segment.line
+        ));
+    }
+
+    for lesson in lessons
+        .iter()
+        .filter(|lesson| segment.defines(lesson.entry_name()))
+    {
+        for diagram in lesson.diagrams() {
+            markdown.push_str(&format!("![{}]({})\n\n", diagram, image_link(diagram)));
+        }
+    }
+    markdown
+}
+
+// Functions that feed the lesson runner, `practice_one layout` and `practice_one borrows`
+const PLUMBING: &[&str] = &["register", "layouts", "borrow_rules_snippet"];
+
+// The plumbing is not part of the tutorial
+pub fn is_lesson_content(segment: &Segment) -> bool {
+    !PLUMBING.iter().any(|function| segment.defines(function))
+}
+
+pub fn chapter(registry: &Registry, tutorial: u8) -> String {
+    let lessons: Vec<&dyn Lesson> = registry.lessons_in(tutorial).collect();
+    let mut markdown = format!("# Tutorial {}\n\n", tutorial);
+
+    markdown.push_str("Lessons in this chapter:\n\n");
+    for lesson in &lessons {
+        markdown.push_str(&format!(
+            "- {} (`practice_one tut{} {}`)\n",
+            lesson.title(),
+            tutorial,
+            lesson.id()
+        ));
+    }
+    markdown.push('\n');
+
+    let source = source::tutorial_source(tutorial).unwrap_or_default();
+    for segment in source::segments(source)
+        .iter()
+        .filter(|segment| is_lesson_content(segment))
+    {
+        markdown.push_str(&segment_markdown(tutorial, segment, &lessons));
+    }
+    markdown.truncate(markdown.trim_end().len());
+    markdown.push('\n');
+    markdown
+}
+
+pub fn table_of_contents(registry: &Registry) -> String {
+    let mut markdown = String::from("# Rust tutorials\n\n");
+    for tutorial in registry.tutorials() {
+        markdown.push_str(&format!(
+            "- [Tutorial {}]({})\n",
+            tutorial,
+            chapter_file(tutorial)
+        ));
+        for lesson in registry.lessons_in(tutorial) {
+            markdown.push_str(&format!("    - {}: {}\n", lesson.id(), lesson.title()));
+        }
+    }
+    markdown
+}
+
+// Writes README.md, one tutN.md per tutorial and the images into `out_dir`
+pub fn generate(registry: &Registry, out_dir: &Path) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(out_dir)?;
+    let mut written = Vec::new();
+
+    let readme = out_dir.join("README.md");
+    fs::write(&readme, table_of_contents(registry))?;
+    written.push(readme);
+
+    for tutorial in registry.tutorials() {
+        let path = out_dir.join(chapter_file(tutorial));
+        fs::write(&path, chapter(registry, tutorial))?;
+        written.push(path);
+    }
+
+    copy_images(out_dir)?;
+    Ok(written)
+}
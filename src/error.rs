@@ -0,0 +1,189 @@
+// Errors
+//
+// One error type for everything in the crate that can fail, so callers can match on what went
+// wrong instead of reading a message. Each variant only describes itself, the error that caused it
+// is available through `source()`, e.g. "could not write the docs to docs" caused by
+// "Permission denied (os error 13)".
+
+use std::{
+    cell::{Cell, RefCell},
+    error, fmt, io,
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+#[derive(Debug)]
+pub enum Error {
+    // A value that has the right type but isn't acceptable, e.g. a failed condition in a lesson or
+    // an exercise whose tests don't pass yet
+    Validation(String),
+    // Text that doesn't follow its format, like a question bank, with the 1-based line of the problem
+    // and, when it's known, the 1-based column
+    Parse {
+        line: usize,
+        column: Option<usize>,
+        message: String,
+    },
+    Io(io::Error),
+    // A lesson, tutorial, quiz or exercise that doesn't exist, with the names that do
+    NotFound {
+        what: &'static str,
+        name: String,
+        available: Vec<String>,
+    },
+    // Code that panicked instead of returning an error, caught by `catch_panic`
+    Panic {
+        message: String,
+        location: Option<String>,
+    },
+    // What was being done when `source` happened
+    Context {
+        message: String,
+        source: Box<Error>,
+    },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn context(self, message: impl Into<String>) -> Error {
+        Error::Context {
+            message: message.into(),
+            source: Box::new(self),
+        }
+    }
+
+    // The error under all the context, the one to match on to know what kind of failure it was
+    pub fn root(&self) -> &Error {
+        match self {
+            Error::Context { source, .. } => source.root(),
+            other => other,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Validation(message) => write!(f, "{}", message),
+            Error::Parse {
+                line,
+                column: None,
+                message,
+            } => write!(f, "line {}: {}", line, message),
+            Error::Parse {
+                line,
+                column: Some(column),
+                message,
+            } => write!(f, "line {}, column {}: {}", line, column, message),
+            Error::Io(error) => write!(f, "{}", error),
+            Error::NotFound {
+                what,
+                name,
+                available,
+            } => {
+                write!(f, "unknown {} `{}`", what, name)?;
+                if !available.is_empty() {
+                    write!(f, ", available: {}", available.join(", "))?;
+                }
+                Ok(())
+            }
+            Error::Panic {
+                message,
+                location: Some(location),
+            } => write!(f, "panicked at {}: {}", location, message),
+            Error::Panic { message, .. } => write!(f, "panicked: {}", message),
+            Error::Context { message, .. } => write!(f, "{}", message),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(error) => error.source(),
+            Error::Context { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+// The error followed by the numbered chain of what caused it:
+//
+//     error: could not write the docs to out
+//
+//     caused by:
+//         1: Permission denied (os error 13)
+pub fn report(error: &dyn error::Error) -> String {
+    let mut report = format!("error: {}\n", error);
+    let mut source = error.source();
+    if source.is_some() {
+        report.push_str("\ncaused by:\n");
+    }
+    let mut number = 1;
+    while let Some(cause) = source {
+        report.push_str(&format!("    {}: {}\n", number, cause));
+        number += 1;
+        source = cause.source();
+    }
+    report
+}
+
+thread_local! {
+    // Set while this thread is inside catch_panic, its panics are recorded instead of printed
+    static CAPTURING: Cell<bool> = const { Cell::new(false) };
+    // Message and location of the last panic on this thread, filled by the hook below
+    static LAST_PANIC: RefCell<Option<(String, Option<String>)>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+// The panic hook is global to the process, so it's installed once and never swapped back. It only
+// records panics of threads inside catch_panic and hands every other panic to the previous hook.
+fn install_hook() {
+    INSTALL_HOOK.call_once(|| {
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CAPTURING.with(Cell::get) {
+                return previous_hook(info);
+            }
+            let location = info
+                .location()
+                .map(|location| format!("{}:{}", location.file(), location.line()));
+            let message = panic_message(info.payload());
+            LAST_PANIC.with(|last| *last.borrow_mut() = Some((message, location)));
+        }));
+    });
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+// Runs `f` and turns a panic into Error::Panic, so one broken lesson doesn't take the whole
+// runner down. The panic is recorded instead of printed, so it isn't reported twice.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T> {
+    install_hook();
+    let was_capturing = CAPTURING.with(|capturing| capturing.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CAPTURING.with(|capturing| capturing.set(was_capturing));
+
+    result.map_err(|payload| {
+        let (message, location) = LAST_PANIC
+            .with(|last| last.borrow_mut().take())
+            .unwrap_or_else(|| (panic_message(payload.as_ref()), None));
+        Error::Panic { message, location }
+    })
+}
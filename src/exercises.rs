@@ -0,0 +1,217 @@
+// Exercises with hidden tests
+//
+// Each exercise is a folder in exercises/ with the stub the learner fills in (exercise.rs), the
+// tests that check it (tests.rs) and a reference solution (solution.rs). Checking an exercise glues both into one file, compiles it with
+// the local rustc as a test binary and runs it. A `// hint:` comment right above a test is shown
+// when that test fails.
+
+use crate::error::{Error, Result};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+pub struct Exercise {
+    pub name: &'static str,
+    pub tutorial: u8,
+    pub description: &'static str,
+}
+
+pub const EXERCISES: &[Exercise] = &[
+    Exercise {
+        name: "tut2_parse_counts",
+        tutorial: 2,
+        description: "parse and add up counts with Result, Option and ?",
+    },
+    Exercise {
+        name: "tut3_ownership",
+        tutorial: 3,
+        description: "take, borrow and mutably borrow Strings",
+    },
+    Exercise {
+        name: "tut4_longest",
+        tutorial: 4,
+        description: "return borrowed names from functions and structs with lifetimes",
+    },
+    Exercise {
+        name: "tut5_coordinates",
+        tutorial: 5,
+        description: "use tuple structs, the struct update syntax and an enum",
+    },
+    Exercise {
+        name: "tut6_fizzbuzz",
+        tutorial: 6,
+        description: "write fizzbuzz with if-else, a for loop and a while loop",
+    },
+    Exercise {
+        name: "tut7_match_enum",
+        tutorial: 7,
+        description: "write a match over ScriptSagaEnum",
+    },
+    Exercise {
+        name: "tut8_classify",
+        tutorial: 8,
+        description: "classify values with ranges, match guards and @ bindings",
+    },
+    Exercise {
+        name: "tut9_role",
+        tutorial: 9,
+        description: "implement Role for new structs",
+    },
+    Exercise {
+        name: "tut10_followers_map",
+        tutorial: 10,
+        description: "build and query a HashMap<i32, String> of followers",
+    },
+    Exercise {
+        name: "tut11_notes",
+        tutorial: 11,
+        description: "read and append notes, recovering from a missing file",
+    },
+];
+
+pub struct TestResult {
+    pub name: String,
+    pub passed: bool,
+    pub hint: Option<String>,
+}
+
+pub enum CheckReport {
+    // The exercise doesn't compile yet, with the compiler output
+    CompileError(String),
+    Ran(Vec<TestResult>),
+}
+
+pub fn find(name: &str) -> Option<&'static Exercise> {
+    EXERCISES.iter().find(|exercise| exercise.name == name)
+}
+
+pub fn exercise_dir(exercise: &Exercise) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("exercises")
+        .join(exercise.name)
+}
+
+// test name -> hint, taken from `// hint:` comments directly above `#[test]`
+fn hints(tests: &str) -> Vec<(String, String)> {
+    let mut hints = Vec::new();
+    let mut pending: Option<String> = None;
+    let mut is_test = false;
+
+    for line in tests.lines().map(str::trim) {
+        if let Some(hint) = line.strip_prefix("// hint:") {
+            pending = Some(hint.trim().to_string());
+        } else if line == "#[test]" {
+            is_test = true;
+        } else if let Some(rest) = line.strip_prefix("fn ").filter(|_| is_test) {
+            let name: String = rest
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || *c == '_')
+                .collect();
+            if let Some(hint) = pending.take() {
+                hints.push((name, hint));
+            }
+            is_test = false;
+        } else if !line.is_empty() {
+            pending = None;
+        }
+    }
+    hints
+}
+
+// The stub followed by the hidden tests in a child module, so the tests see the learner's items
+fn test_crate(stub: &str, tests: &str) -> String {
+    let tests: String = tests
+        .lines()
+        .map(|line| format!("    {}\n", line).trim_end().to_string() + "\n")
+        .collect();
+    format!(
+        "{}\n\n#[cfg(test)]\nmod hidden_tests {{\n    use super::*;\n\n{}}}\n",
+        stub, tests
+    )
+}
+
+// `test hidden_tests::name ... ok` lines of the libtest output
+fn parse_results(output: &str, hints: &[(String, String)]) -> Vec<TestResult> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let rest = line.strip_prefix("test hidden_tests::")?;
+            let (name, status) = rest.split_once(" ... ")?;
+            let hint = hints
+                .iter()
+                .find(|(test, _)| test == name)
+                .map(|(_, hint)| hint.clone());
+            Some(TestResult {
+                name: name.to_string(),
+                passed: status.trim() == "ok",
+                hint,
+            })
+        })
+        .collect()
+}
+
+// Runs the hidden tests against the learner's exercise.rs
+pub fn check(exercise: &Exercise) -> Result<CheckReport> {
+    check_file(exercise, "exercise.rs")
+}
+
+// Runs the hidden tests against the reference solution, which has to pass all of them
+pub fn check_solution(exercise: &Exercise) -> Result<CheckReport> {
+    check_file(exercise, "solution.rs")
+}
+
+fn check_file(exercise: &Exercise, file: &str) -> Result<CheckReport> {
+    let dir = exercise_dir(exercise);
+    let read = |file: &str| {
+        let path = dir.join(file);
+        fs::read_to_string(&path).map_err(|error| {
+            Error::from(error).context(format!("could not read {}", path.display()))
+        })
+    };
+    let stub = read(file)?;
+    let tests = read("tests.rs")?;
+
+    // One directory per exercise and file, so checking the stub and the solution at the same
+    // time doesn't mix them up
+    let work_dir = env::temp_dir().join(format!(
+        "practice_one-check-{}-{}-{}",
+        exercise.name,
+        file.trim_end_matches(".rs"),
+        std::process::id()
+    ));
+    fs::create_dir_all(&work_dir).map_err(|error| {
+        Error::from(error).context(format!("could not create {}", work_dir.display()))
+    })?;
+    let source = work_dir.join("check.rs");
+    let binary = work_dir.join("check");
+    fs::write(&source, test_crate(&stub, &tests)).map_err(|error| {
+        Error::from(error).context(format!("could not write {}", source.display()))
+    })?;
+
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let compiled = Command::new(&rustc)
+        .args(["--edition", "2021", "--test", "-A", "warnings", "-o"])
+        .arg(&binary)
+        .arg(&source)
+        .output()
+        .map_err(|error| Error::from(error).context(format!("could not run {}", rustc)))?;
+
+    let report = if !compiled.status.success() {
+        CheckReport::CompileError(String::from_utf8_lossy(&compiled.stderr).into_owned())
+    } else {
+        let ran = Command::new(&binary)
+            .arg("--test-threads=1")
+            .env("RUST_BACKTRACE", "0")
+            .output()
+            .map_err(|error| Error::from(error).context("could not run the tests"))?;
+        CheckReport::Ran(parse_results(
+            &String::from_utf8_lossy(&ran.stdout),
+            &hints(&tests),
+        ))
+    };
+
+    fs::remove_dir_all(&work_dir).ok();
+    Ok(report)
+}
@@ -0,0 +1,162 @@
+// Reading follower records
+//
+// A follower list has one follower per line, the first and the last name separated by a delimiter:
+//
+//     # first name, last name
+//     script, saga
+//     ferris, crab
+//
+// Blank lines and lines starting with # are skipped and the spaces around a name are trimmed.
+//
+// Followers doesn't copy anything: every Follower it yields borrows both names straight out of
+// the text. tut4::ScriptSagaFollower can't be that follower, its last name is an owned String, so
+// the parser has a borrowed type of its own. It turns into a ScriptSagaFollower on request, but
+// that copies the last name. The text has to outlive the followers, and in return reading a list
+// costs no allocation at all. parse_owned is the usual version that copies every name into a
+// String, benches/followers.rs compares the two.
+
+use crate::{
+    error::{Error, Result},
+    lessons::tut4::ScriptSagaFollower,
+};
+use std::str::Lines;
+
+// A follower record as it is in the text, both names borrowed from it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Follower<'a> {
+    pub first_name: &'a str,
+    pub last_name: &'a str,
+}
+
+// Not zero-copy: the tutorial's follower borrows the first name too, but its last name is a String
+// and gets copied
+impl<'a> From<Follower<'a>> for ScriptSagaFollower<'a> {
+    fn from(follower: Follower<'a>) -> Self {
+        ScriptSagaFollower::new(follower.first_name, follower.last_name.to_string())
+    }
+}
+
+pub const DEFAULT_DELIMITER: char = ',';
+
+// The first and last name of a record, or None for a line to skip
+fn split_record(line: &str, number: usize, delimiter: char) -> Result<Option<(&str, &str)>> {
+    let trimmed = line.trim();
+    if trimmed.is_empty() || trimmed.starts_with('#') {
+        return Ok(None);
+    }
+
+    // 1-based column of a byte offset into the line, counted in characters
+    let column = |offset: usize| line[..offset].chars().count() + 1;
+    let invalid = |offset: usize, message: String| Error::Parse {
+        line: number,
+        column: Some(column(offset)),
+        message,
+    };
+
+    // Only looking for the delimiter, so nothing gets allocated
+    let mut first = None;
+    let mut start = 0;
+    for (offset, _) in line.match_indices(delimiter) {
+        if first.is_some() {
+            return Err(invalid(
+                offset,
+                format!("expected 2 fields, found another `{}`", delimiter),
+            ));
+        }
+        first = Some((start, &line[start..offset]));
+        start = offset + delimiter.len_utf8();
+    }
+    let Some((first_start, first_name)) = first else {
+        return Err(invalid(
+            line.trim_end().len(),
+            format!("expected `{}` and a last name", delimiter),
+        ));
+    };
+    let last_name = &line[start..];
+
+    for (offset, field, which) in [
+        (first_start, first_name, "first name"),
+        (start, last_name, "last name"),
+    ] {
+        if field.trim().is_empty() {
+            // Point at the empty spot, after any spaces
+            let offset = offset + field.len() - field.trim_start().len();
+            return Err(invalid(offset, format!("the {} is empty", which)));
+        }
+    }
+    Ok(Some((first_name.trim(), last_name.trim())))
+}
+
+// Iterator over the followers of a text. A bad line gives an error and reading carries on with the
+// next line, so collecting into Result<Vec<_>> stops at the first error and a plain loop can report
+// all of them.
+pub struct Followers<'a> {
+    lines: Lines<'a>,
+    number: usize,
+    delimiter: char,
+}
+
+impl<'a> Followers<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Followers {
+            lines: text.lines(),
+            number: 0,
+            delimiter: DEFAULT_DELIMITER,
+        }
+    }
+
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+}
+
+impl<'a> Iterator for Followers<'a> {
+    type Item = Result<Follower<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for line in self.lines.by_ref() {
+            self.number += 1;
+            match split_record(line, self.number, self.delimiter) {
+                Ok(Some((first_name, last_name))) => {
+                    return Some(Ok(Follower {
+                        first_name,
+                        last_name,
+                    }))
+                }
+                Ok(None) => {}
+                Err(error) => return Some(Err(error)),
+            }
+        }
+        None
+    }
+}
+
+pub fn parse(text: &str, delimiter: char) -> Result<Vec<Follower<'_>>> {
+    Followers::new(text).delimiter(delimiter).collect()
+}
+
+// A follower that owns its names, what parse_owned gives
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnedFollower {
+    pub first_name: String,
+    pub last_name: String,
+}
+
+impl From<Follower<'_>> for OwnedFollower {
+    fn from(follower: Follower<'_>) -> Self {
+        OwnedFollower {
+            first_name: follower.first_name.to_string(),
+            last_name: follower.last_name.to_string(),
+        }
+    }
+}
+
+// The same format, copying every name into its own String. The followers don't borrow the text, so
+// it can be dropped right after, at the cost of two allocations per follower.
+pub fn parse_owned(text: &str, delimiter: char) -> Result<Vec<OwnedFollower>> {
+    Followers::new(text)
+        .delimiter(delimiter)
+        .map(|follower| follower.map(OwnedFollower::from))
+        .collect()
+}
@@ -0,0 +1,219 @@
+// A small Rust tokenizer for syntax highlighting
+//
+// It only needs to be good enough to colour the tutorial code, so it knows about comments,
+// strings, chars, lifetimes, numbers, keywords, macros and types and treats everything else as
+// punctuation.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Whitespace,
+    Comment,
+    String,
+    Char,
+    Lifetime,
+    Number,
+    Keyword,
+    Macro,
+    Type,
+    Identifier,
+    Punctuation,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub kind: TokenKind,
+    pub text: &'a str,
+}
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while",
+];
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+// Length in bytes of the token starting at the beginning of `rest`
+fn token_length(rest: &str) -> (TokenKind, usize) {
+    let chars: Vec<(usize, char)> = rest.char_indices().collect();
+    let char_at = |i: usize| chars.get(i).map(|(_, c)| *c);
+    let byte_at = |i: usize| chars.get(i).map_or(rest.len(), |(offset, _)| *offset);
+    let first = chars[0].1;
+
+    if first.is_whitespace() {
+        let end = chars
+            .iter()
+            .position(|(_, c)| !c.is_whitespace())
+            .unwrap_or(chars.len());
+        return (TokenKind::Whitespace, byte_at(end));
+    }
+
+    if rest.starts_with("//") {
+        return (TokenKind::Comment, rest.find('\n').unwrap_or(rest.len()));
+    }
+
+    if rest.starts_with("/*") {
+        // Block comments nest in Rust
+        let mut depth = 0;
+        let mut i = 0;
+        while i < chars.len() {
+            match (char_at(i), char_at(i + 1)) {
+                (Some('/'), Some('*')) => {
+                    depth += 1;
+                    i += 2;
+                }
+                (Some('*'), Some('/')) => {
+                    depth -= 1;
+                    i += 2;
+                    if depth == 0 {
+                        return (TokenKind::Comment, byte_at(i));
+                    }
+                }
+                _ => i += 1,
+            }
+        }
+        return (TokenKind::Comment, rest.len());
+    }
+
+    // Raw strings: r"..." and r#"..."#, optionally as byte strings
+    let raw_start = if rest.starts_with("br") {
+        2
+    } else if rest.starts_with('r') {
+        1
+    } else {
+        0
+    };
+    if raw_start > 0 {
+        let hashes = rest[raw_start..].chars().take_while(|c| *c == '#').count();
+        if rest[raw_start + hashes..].starts_with('"') {
+            let closing = format!("\"{}", "#".repeat(hashes));
+            let body = raw_start + hashes + 1;
+            let end = rest[body..]
+                .find(&closing)
+                .map_or(rest.len(), |i| body + i + closing.len());
+            return (TokenKind::String, end);
+        }
+    }
+
+    let string_start = if rest.starts_with("b\"") { 1 } else { 0 };
+    if rest[string_start..].starts_with('"') {
+        let mut i = string_start + 1;
+        while let Some(c) = char_at(i) {
+            match c {
+                '\\' => i += 2,
+                '"' => return (TokenKind::String, byte_at(i + 1)),
+                _ => i += 1,
+            }
+        }
+        return (TokenKind::String, rest.len());
+    }
+
+    if first == '\'' {
+        // 'x' and '\n' are chars, 'a without a closing quote is a lifetime
+        if char_at(1) == Some('\\') {
+            // The escaped char itself can be a quote, as in '\'', so the search starts after it
+            let end = (3..chars.len())
+                .find(|i| char_at(*i) == Some('\''))
+                .unwrap_or(chars.len() - 1);
+            return (TokenKind::Char, byte_at(end + 1));
+        }
+        if char_at(2) == Some('\'') {
+            return (TokenKind::Char, byte_at(3));
+        }
+        let end = (1..chars.len())
+            .find(|i| !char_at(*i).is_some_and(is_identifier_char))
+            .unwrap_or(chars.len());
+        return (TokenKind::Lifetime, byte_at(end));
+    }
+
+    if first.is_ascii_digit() {
+        let mut i = 1;
+        while let Some(c) = char_at(i) {
+            // Stop at `..` so that 0..10 stays a range
+            let decimal_point =
+                c == '.' && char_at(i + 1).is_some_and(|next| next.is_ascii_digit());
+            if !(is_identifier_char(c) || decimal_point) {
+                break;
+            }
+            i += 1;
+        }
+        return (TokenKind::Number, byte_at(i));
+    }
+
+    if is_identifier_char(first) {
+        let end = (0..chars.len())
+            .find(|i| !char_at(*i).is_some_and(is_identifier_char))
+            .unwrap_or(chars.len());
+        let word = &rest[..byte_at(end)];
+        let kind = if KEYWORDS.contains(&word) {
+            TokenKind::Keyword
+        } else if char_at(end) == Some('!') && char_at(end + 1) != Some('=') {
+            return (TokenKind::Macro, byte_at(end + 1));
+        } else if first.is_uppercase() {
+            TokenKind::Type
+        } else {
+            TokenKind::Identifier
+        };
+        return (kind, word.len());
+    }
+
+    (TokenKind::Punctuation, first.len_utf8())
+}
+
+pub fn tokenize(code: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = code;
+
+    while !rest.is_empty() {
+        let (kind, length) = token_length(rest);
+        let (text, remaining) = rest.split_at(length);
+        tokens.push(Token { kind, text });
+        rest = remaining;
+    }
+    tokens
+}
+
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '&' => escaped.push_str("&amp;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn css_class(kind: TokenKind) -> Option<&'static str> {
+    match kind {
+        TokenKind::Comment => Some("comment"),
+        TokenKind::String | TokenKind::Char => Some("string"),
+        TokenKind::Lifetime => Some("lifetime"),
+        TokenKind::Number => Some("number"),
+        TokenKind::Keyword => Some("keyword"),
+        TokenKind::Macro => Some("macro"),
+        TokenKind::Type => Some("type"),
+        TokenKind::Whitespace | TokenKind::Identifier | TokenKind::Punctuation => None,
+    }
+}
+
+// Rust code as HTML with a <span class="..."> around every highlighted token
+pub fn highlight_html(code: &str) -> String {
+    tokenize(code)
+        .iter()
+        .map(|token| match css_class(token.kind) {
+            Some(class) => format!(
+                "<span class=\"{}\">{}</span>",
+                class,
+                escape_html(token.text)
+            ),
+            None => escape_html(token.text),
+        })
+        .collect()
+}
@@ -0,0 +1,170 @@
+// Static HTML export of the tutorials
+//
+// Same content as the Markdown chapters, but as a self-contained site: an index page, one page
+// per tutorial with the code highlighted by the built-in tokenizer, and a copy of images/. The
+// pages only link to each other and to the copied images, so the folder can be served from any
+// file share.
+
+use crate::{
+    docs,
+    highlight::{escape_html, highlight_html},
+    registry::{Lesson, Registry},
+    source::{self, Segment},
+};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+const STYLE: &str = "
+body { font-family: sans-serif; max-width: 960px; margin: 2em auto; padding: 0 1em; line-height: 1.5; color: #222; }
+nav { margin-bottom: 2em; }
+.explanation { white-space: pre-wrap; }
+pre { background: #1e1e1e; color: #d4d4d4; padding: 1em; overflow-x: auto; border-radius: 6px; }
+.source { color: #888; font-size: 0.85em; }
+img { max-width: 100%; border: 1px solid #ddd; margin: 1em 0; }
+.comment { color: #6a9955; }
+.string { color: #ce9178; }
+.lifetime { color: #c586c0; }
+.number { color: #b5cea8; }
+.keyword { color: #569cd6; }
+.macro { color: #dcdcaa; }
+.type { color: #4ec9b0; }
+";
+
+fn page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>{}</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+        escape_html(title),
+        STYLE,
+        body
+    )
+}
+
+pub fn page_file(tutorial: u8) -> String {
+    format!("tut{}.html", tutorial)
+}
+
+fn navigation(registry: &Registry, tutorial: u8) -> String {
+    let tutorials = registry.tutorials();
+    let position = tutorials.iter().position(|number| *number == tutorial);
+    let mut links = vec!["<a href=\"index.html\">All tutorials</a>".to_string()];
+
+    if let Some(previous) = position
+        .and_then(|index| index.checked_sub(1))
+        .map(|index| tutorials[index])
+    {
+        links.push(format!(
+            "<a href=\"{}\">&larr; Tutorial {}</a>",
+            page_file(previous),
+            previous
+        ));
+    }
+    if let Some(next) = position.and_then(|index| tutorials.get(index + 1)) {
+        links.push(format!(
+            "<a href=\"{}\">Tutorial {} &rarr;</a>",
+            page_file(*next),
+            next
+        ));
+    }
+    format!("<nav>{}</nav>\n", links.join(" | "))
+}
+
+fn segment_html(tutorial: u8, segment: &Segment, lessons: &[&dyn Lesson]) -> String {
+    let mut html = String::new();
+
+    let text = segment.comment.join("\n");
+    if !text.trim().is_empty() {
+        html.push_str(&format!(
+            "<p class=\"explanation\">{}</p>\n",
+            escape_html(text.trim())
+        ));
+    }
+
+    if !segment.code.is_empty() {
+        html.push_str(&format!(
+            "<pre><code>{}</code></pre>\n<p class=\"source\">src/lessons/tut{}.rs, line {}</p>\n",
+            highlight_html(&segment.code.join("\n")),
+            tutorial,
+            segment.line
+        ));
+    }
+
+    for lesson in lessons
+        .iter()
+        .filter(|lesson| segment.defines(lesson.entry_name()))
+    {
+        for diagram in lesson.diagrams() {
+            html.push_str(&format!(
+                "<img src=\"{}\" alt=\"{}\">\n",
+                docs::image_link(diagram),
+                escape_html(diagram)
+            ));
+        }
+    }
+    html
+}
+
+pub fn tutorial_page(registry: &Registry, tutorial: u8) -> String {
+    let lessons: Vec<&dyn Lesson> = registry.lessons_in(tutorial).collect();
+    let mut body = navigation(registry, tutorial);
+
+    body.push_str(&format!("<h1>Tutorial {}</h1>\n<ul>\n", tutorial));
+    for lesson in &lessons {
+        body.push_str(&format!(
+            "<li>{} <code>practice_one tut{} {}</code></li>\n",
+            escape_html(lesson.title()),
+            tutorial,
+            lesson.id()
+        ));
+    }
+    body.push_str("</ul>\n");
+
+    let source = source::tutorial_source(tutorial).unwrap_or_default();
+    for segment in source::segments(source)
+        .iter()
+        .filter(|segment| docs::is_lesson_content(segment))
+    {
+        body.push_str(&segment_html(tutorial, segment, &lessons));
+    }
+    body.push_str(&navigation(registry, tutorial));
+
+    page(&format!("Tutorial {}", tutorial), &body)
+}
+
+pub fn index_page(registry: &Registry) -> String {
+    let mut body = String::from("<h1>Rust tutorials</h1>\n<ul>\n");
+    for tutorial in registry.tutorials() {
+        body.push_str(&format!(
+            "<li><a href=\"{}\">Tutorial {}</a>\n<ul>\n",
+            page_file(tutorial),
+            tutorial
+        ));
+        for lesson in registry.lessons_in(tutorial) {
+            body.push_str(&format!("<li>{}</li>\n", escape_html(lesson.title())));
+        }
+        body.push_str("</ul>\n</li>\n");
+    }
+    body.push_str("</ul>\n");
+
+    page("Rust tutorials", &body)
+}
+
+// Writes index.html, one tutN.html per tutorial and the images into `out_dir`
+pub fn generate(registry: &Registry, out_dir: &Path) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(out_dir)?;
+    let mut written = Vec::new();
+
+    let index = out_dir.join("index.html");
+    fs::write(&index, index_page(registry))?;
+    written.push(index);
+
+    for tutorial in registry.tutorials() {
+        let path = out_dir.join(page_file(tutorial));
+        fs::write(&path, tutorial_page(registry, tutorial))?;
+        written.push(path);
+    }
+
+    docs::copy_images(out_dir)?;
+    Ok(written)
+}
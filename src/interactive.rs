@@ -0,0 +1,204 @@
+// Interactive lesson browser
+//
+// A small menu over stdin/stdout: pick a lesson, read the explanation taken from the comments of
+// the tutorial, see what the lesson prints and move on to the next one.
+
+use crate::{
+    error::{self, Error, Result},
+    progress::{self, Progress},
+    registry::{Lesson, Registry},
+    source,
+};
+use std::io::{self, BufRead, Write};
+
+const LESSON_PROMPT: &str = "[n]ext  [p]revious  [r]epeat  [m]enu  [q]uit > ";
+
+enum Choice {
+    Lesson(usize),
+    Menu,
+    Quit,
+}
+
+fn read_line(
+    input: &mut dyn BufRead,
+    out: &mut dyn Write,
+    prompt: &str,
+) -> io::Result<Option<String>> {
+    write!(out, "{}", prompt)?;
+    out.flush()?;
+
+    let mut line = String::new();
+    if input.read_line(&mut line)? == 0 {
+        // End of input behaves like quit
+        writeln!(out)?;
+        return Ok(None);
+    }
+    Ok(Some(line.trim().to_ascii_lowercase()))
+}
+
+fn print_menu(lessons: &[&dyn Lesson], out: &mut dyn Write) -> io::Result<()> {
+    let mut current = 0;
+    for (index, lesson) in lessons.iter().enumerate() {
+        if lesson.tutorial() != current {
+            current = lesson.tutorial();
+            writeln!(out, "tut{}", current)?;
+        }
+        writeln!(
+            out,
+            "  {:>2}. {:<28}{}",
+            index + 1,
+            lesson.id(),
+            lesson.title()
+        )?;
+    }
+    Ok(())
+}
+
+// Fails when the lesson does, the output up to there is shown either way
+pub fn show_lesson(lesson: &dyn Lesson, out: &mut dyn Write) -> Result<()> {
+    writeln!(out)?;
+    writeln!(
+        out,
+        "=== {} - {} ===",
+        lesson.qualified_id(),
+        lesson.title()
+    )?;
+
+    let explanation = source::tutorial_source(lesson.tutorial())
+        .and_then(|source| source::explanation(source, lesson.entry_name()));
+    if let Some(explanation) = explanation {
+        writeln!(out)?;
+        writeln!(out, "{}", explanation)?;
+    }
+    for diagram in lesson.diagrams() {
+        writeln!(out, "(diagram: images/{})", diagram)?;
+    }
+
+    writeln!(out)?;
+    writeln!(
+        out,
+        "--- output of tut{}::{} ---",
+        lesson.tutorial(),
+        lesson.entry_name()
+    )?;
+    // A panicking lesson is a failure like any other, the browser carries on
+    let ran = error::catch_panic(|| lesson.run(out))
+        .and_then(|written| written.map_err(Error::from))
+        .map_err(|error| error.context(format!("{} failed", lesson.qualified_id())));
+    writeln!(out, "--- end of output ---")?;
+    ran
+}
+
+fn pick_from_menu(
+    lessons: &[&dyn Lesson],
+    input: &mut dyn BufRead,
+    out: &mut dyn Write,
+) -> io::Result<Choice> {
+    loop {
+        writeln!(out)?;
+        print_menu(lessons, out)?;
+        let Some(answer) = read_line(input, out, "pick a lesson by number or name ([q]uit) > ")?
+        else {
+            return Ok(Choice::Quit);
+        };
+
+        if answer == "q" || answer == "quit" {
+            return Ok(Choice::Quit);
+        }
+        let index = answer
+            .parse::<usize>()
+            .ok()
+            .filter(|number| (1..=lessons.len()).contains(number))
+            .map(|number| number - 1)
+            .or_else(|| {
+                lessons
+                    .iter()
+                    .position(|lesson| lesson.id() == answer || lesson.qualified_id() == answer)
+            });
+        match index {
+            Some(index) => return Ok(Choice::Lesson(index)),
+            None => writeln!(out, "no lesson `{}`, try again", answer)?,
+        }
+    }
+}
+
+fn after_lesson(
+    current: usize,
+    count: usize,
+    input: &mut dyn BufRead,
+    out: &mut dyn Write,
+) -> io::Result<Choice> {
+    loop {
+        let Some(answer) = read_line(input, out, LESSON_PROMPT)? else {
+            return Ok(Choice::Quit);
+        };
+
+        match answer.as_str() {
+            "n" | "next" | "" if current + 1 < count => return Ok(Choice::Lesson(current + 1)),
+            "n" | "next" | "" => writeln!(out, "that was the last lesson")?,
+            "p" | "previous" if current > 0 => return Ok(Choice::Lesson(current - 1)),
+            "p" | "previous" => writeln!(out, "that was the first lesson")?,
+            "r" | "repeat" => return Ok(Choice::Lesson(current)),
+            "m" | "menu" => return Ok(Choice::Menu),
+            "q" | "quit" => return Ok(Choice::Quit),
+            other => writeln!(out, "unknown choice `{}`", other)?,
+        }
+    }
+}
+
+// Offers to continue after the last lesson the learner finished, an empty answer means yes
+fn resume(
+    registry: &Registry,
+    progress: &Progress,
+    lessons: &[&dyn Lesson],
+    input: &mut dyn BufRead,
+    out: &mut dyn Write,
+) -> io::Result<Choice> {
+    let (Some(last), Some(index)) = (progress.last_lesson(), progress.resume_index(registry))
+    else {
+        return Ok(Choice::Menu);
+    };
+
+    writeln!(
+        out,
+        "Welcome back {}, last time you did {} on {}",
+        progress.learner(),
+        last.id,
+        progress::format_timestamp(last.timestamp)
+    )?;
+    let prompt = format!("resume with {}? [Y/n] > ", lessons[index].qualified_id());
+    match read_line(input, out, &prompt)?.as_deref() {
+        None => Ok(Choice::Quit),
+        Some("" | "y" | "yes") => Ok(Choice::Lesson(index)),
+        Some(_) => Ok(Choice::Menu),
+    }
+}
+
+pub fn run(
+    registry: &Registry,
+    progress: &mut Progress,
+    input: &mut dyn BufRead,
+    out: &mut dyn Write,
+) -> io::Result<()> {
+    let lessons: Vec<&dyn Lesson> = registry.lessons().collect();
+    let mut choice = resume(registry, progress, &lessons, input, out)?;
+
+    loop {
+        choice = match choice {
+            Choice::Menu => pick_from_menu(&lessons, input, out)?,
+            Choice::Lesson(index) => {
+                // Like the lesson runner, only a lesson that worked counts as done
+                match show_lesson(lessons[index], out) {
+                    Ok(()) => {
+                        if let Err(error) = progress.record_lesson(lessons[index]) {
+                            writeln!(out, "(could not save progress: {})", error)?;
+                        }
+                    }
+                    Err(error) => write!(out, "{}", error::report(&error))?,
+                }
+                after_lesson(index, lessons.len(), input, out)?
+            }
+            Choice::Quit => return Ok(()),
+        };
+    }
+}
@@ -0,0 +1,97 @@
+// Interning strings
+//
+// Follower names repeat a lot, and every String holding "script_saga_follower_one" is another heap
+// allocation with another copy of the same bytes. An Interner keeps each distinct string once and
+// hands out a Symbol for it, a 4 byte id that is cheap to copy, compare and hash. resolve turns the
+// symbol back into a &str borrowed from the interner, so the string can't be used after the
+// interner is gone.
+//
+// Every string is stored once as an Rc<str>, shared by the map that finds the symbol of a string
+// and the list that finds the string of a symbol. The map is looked up with a plain &str, so asking
+// for a string that is already interned never allocates.
+//
+// Rc makes an Interner !Send and !Sync, it stays on the thread that made it. That's all the lessons
+// need, and Rc's counts are plain integers where Arc's would be atomic. An interner shared between
+// threads would want Arc<str> and a Mutex around it.
+
+use std::{collections::HashMap, fmt, rc::Rc};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    // Symbols are numbered from 0 in the order their strings were first interned
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Interner {
+    symbols: HashMap<Rc<str>, Symbol>,
+    strings: Vec<Rc<str>>,
+}
+
+impl Interner {
+    pub fn new() -> Self {
+        Interner::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Interner {
+            symbols: HashMap::with_capacity(capacity),
+            strings: Vec::with_capacity(capacity),
+        }
+    }
+
+    // The symbol of string, storing the string the first time it's seen
+    pub fn intern(&mut self, string: &str) -> Symbol {
+        if let Some(symbol) = self.get(string) {
+            return symbol;
+        }
+        let symbol =
+            Symbol(u32::try_from(self.strings.len()).expect("more than u32::MAX strings interned"));
+        let string: Rc<str> = Rc::from(string);
+        self.strings.push(Rc::clone(&string));
+        self.symbols.insert(string, symbol);
+        symbol
+    }
+
+    // The symbol of a string that was already interned, without allocating
+    pub fn get(&self, string: &str) -> Option<Symbol> {
+        self.symbols.get(string).copied()
+    }
+
+    // The string of a symbol. A Symbol doesn't know which interner it came from: given the symbol
+    // of another interner this panics if the number is past this interner's strings, and returns
+    // an unrelated string if it isn't.
+    pub fn resolve(&self, symbol: Symbol) -> &str {
+        &self.strings[symbol.index()]
+    }
+
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    // Bytes of all the distinct strings together
+    pub fn bytes(&self) -> usize {
+        self.strings.iter().map(|string| string.len()).sum()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Symbol, &str)> {
+        self.strings
+            .iter()
+            .enumerate()
+            .map(|(index, string)| (Symbol(index as u32), &**string))
+    }
+}
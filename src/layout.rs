@@ -0,0 +1,314 @@
+// Memory layout of the lesson types
+//
+// Size, alignment and field offsets come straight from std::mem (size_of, align_of, offset_of!).
+// offset_of! respects privacy, so every tutorial module lists its own types in a `layouts`
+// function using the macros below. Whether a field owns heap memory is read from its type name,
+// since the compiler doesn't tell.
+//
+// Offsets of fields inside enum variants aren't available on stable Rust, so for enums each
+// variant's payload is shown with its size instead. What's left of the enum next to its largest
+// payload is the tag that says which variant it is plus the padding that rounds the enum up to its
+// alignment; stable Rust can't tell the two apart, so they're shown together. When nothing is left
+// the tag is stored in a niche, a bit pattern the payload can never have (e.g. a null pointer or an
+// impossible capacity).
+
+use std::{
+    fmt::{self, Write as _},
+    mem,
+};
+
+// Types whose values keep their data on the heap
+const HEAP_TYPES: &[&str] = &["String", "Vec", "Box", "HashMap", "Rc", "Arc"];
+
+pub fn owns_heap(type_name: &str) -> bool {
+    type_name
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .any(|word| HEAP_TYPES.contains(&word))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    pub name: &'static str,
+    pub type_name: &'static str,
+    pub offset: usize,
+    pub size: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Variant {
+    pub name: &'static str,
+    pub payload: &'static str,
+    pub size: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Shape {
+    Struct(Vec<Field>),
+    Enum {
+        variants: Vec<Variant>,
+        // size_of::<Option<T>>(), equal to size_of::<T>() when None fits in a niche
+        option_size: usize,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeLayout {
+    // Qualified name, e.g. "tut5::ScriptSagaFollower"
+    pub name: &'static str,
+    pub size: usize,
+    pub align: usize,
+    pub shape: Shape,
+}
+
+impl TypeLayout {
+    pub fn of_struct<T>(name: &'static str, mut fields: Vec<Field>) -> Self {
+        // Fields in memory order, the compiler is free to reorder them
+        fields.sort_by_key(|field| field.offset);
+        TypeLayout {
+            name,
+            size: mem::size_of::<T>(),
+            align: mem::align_of::<T>(),
+            shape: Shape::Struct(fields),
+        }
+    }
+
+    pub fn of_enum<T>(name: &'static str, variants: Vec<Variant>) -> Self {
+        TypeLayout {
+            name,
+            size: mem::size_of::<T>(),
+            align: mem::align_of::<T>(),
+            shape: Shape::Enum {
+                variants,
+                option_size: mem::size_of::<Option<T>>(),
+            },
+        }
+    }
+
+    pub fn owns_heap(&self) -> bool {
+        match &self.shape {
+            Shape::Struct(fields) => fields.iter().any(|field| owns_heap(field.type_name)),
+            Shape::Enum { variants, .. } => {
+                variants.iter().any(|variant| owns_heap(variant.payload))
+            }
+        }
+    }
+
+    // Bytes of a struct that belong to no field, padding added to keep the fields aligned.
+    // None for an enum, or when the fields add up to more than the struct, a table that doesn't
+    // match its type.
+    pub fn padding(&self) -> Option<usize> {
+        match &self.shape {
+            Shape::Struct(fields) => self
+                .size
+                .checked_sub(fields.iter().map(|field| field.size).sum::<usize>()),
+            Shape::Enum { .. } => None,
+        }
+    }
+
+    // Bytes an enum adds next to its largest payload, the tag and the padding after it, e.g. 8 for
+    // a u64 payload with a 1 byte tag. 0 means the tag is in a niche. None for a struct, or when a
+    // payload is larger than the enum itself.
+    pub fn tag_and_padding(&self) -> Option<usize> {
+        match &self.shape {
+            Shape::Enum { variants, .. } => {
+                let largest = variants
+                    .iter()
+                    .map(|variant| variant.size)
+                    .max()
+                    .unwrap_or(0);
+                self.size.checked_sub(largest)
+            }
+            Shape::Struct(_) => None,
+        }
+    }
+}
+
+const INCONSISTENT: &str = "(the table is larger than the type, its field types are wrong)";
+
+fn bytes(count: usize) -> String {
+    if count == 1 {
+        "1 byte".to_string()
+    } else {
+        format!("{} bytes", count)
+    }
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value {
+        "yes"
+    } else {
+        "no"
+    }
+}
+
+impl fmt::Display for TypeLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{}  size {}  align {}  heap {}",
+            self.name,
+            self.size,
+            self.align,
+            yes_no(self.owns_heap())
+        )?;
+
+        match &self.shape {
+            Shape::Struct(fields) if fields.is_empty() => {
+                writeln!(f, "    (no fields, a zero sized type)")?;
+            }
+            Shape::Struct(fields) => {
+                writeln!(
+                    f,
+                    "    {:<16}{:<16}{:>8}{:>6}  heap",
+                    "field", "type", "offset", "size"
+                )?;
+                for field in fields {
+                    writeln!(
+                        f,
+                        "    {:<16}{:<16}{:>8}{:>6}  {}",
+                        field.name,
+                        field.type_name,
+                        field.offset,
+                        field.size,
+                        yes_no(owns_heap(field.type_name))
+                    )?;
+                }
+                match self.padding() {
+                    Some(0) => {}
+                    Some(padding) => writeln!(f, "    padding: {}", bytes(padding))?,
+                    None => writeln!(f, "    {}", INCONSISTENT)?,
+                }
+            }
+            Shape::Enum {
+                variants,
+                option_size,
+            } => {
+                writeln!(
+                    f,
+                    "    {:<20}{:<20}{:>6}  heap",
+                    "variant", "payload", "size"
+                )?;
+                for variant in variants {
+                    writeln!(
+                        f,
+                        "    {:<20}{:<20}{:>6}  {}",
+                        variant.name,
+                        variant.payload,
+                        variant.size,
+                        yes_no(owns_heap(variant.payload))
+                    )?;
+                }
+                match self.tag_and_padding() {
+                    Some(0) => writeln!(f, "    tag: in a niche of the payload, 0 extra bytes")?,
+                    Some(size) => writeln!(
+                        f,
+                        "    tag + padding: {} next to the largest payload",
+                        bytes(size)
+                    )?,
+                    None => writeln!(f, "    {}", INCONSISTENT)?,
+                }
+                let option = if *option_size == self.size {
+                    "the same size, None uses a niche".to_string()
+                } else {
+                    bytes(*option_size)
+                };
+                writeln!(
+                    f,
+                    "    Option<{}>: {}",
+                    self.name.rsplit("::").next().unwrap_or(self.name),
+                    option
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// struct_layout!("tut7::Coordinate", Coordinate { x: i32, y: i32, z: i32 })
+// Tuple structs use the field index as the name: struct_layout!("tut7::Point", Point { 0: i32, ... })
+// The fields are typed in by hand, so the closure that is never called makes the compiler check
+// that they are all there and have those types. A raw pointer doesn't deref-coerce, so a String
+// field listed as str is an error too.
+macro_rules! struct_layout {
+    ($name:expr, $type:ty { $($field:tt : $field_type:ty),* $(,)? }) => {{
+        let _check = |value: &$type| {
+            type Checked = $type;
+            let Checked { $($field: _),* } = value;
+            $(let _: *const $field_type = std::ptr::addr_of!(value.$field);)*
+        };
+        $crate::layout::TypeLayout::of_struct::<$type>(
+            $name,
+            vec![$($crate::layout::Field {
+                name: stringify!($field),
+                type_name: stringify!($field_type),
+                offset: std::mem::offset_of!($type, $field),
+                size: std::mem::size_of::<$field_type>(),
+            }),*],
+        )
+    }};
+}
+
+// enum_layout!("tut5::ScriptSagaEnum", ScriptSagaEnum { SimpleVariant: (), SingleArgs: String })
+// A variant's payload is given as one type, a tuple for variants with several fields. The match
+// that is never run checks that every variant is listed, the payload types can't be checked.
+macro_rules! enum_layout {
+    ($name:expr, $type:ty { $($variant:ident : $payload:ty),* $(,)? }) => {{
+        let _check = |value: &$type| {
+            type Checked = $type;
+            match value {
+                $(Checked::$variant { .. } => {})*
+            }
+        };
+        $crate::layout::TypeLayout::of_enum::<$type>(
+            $name,
+            vec![$($crate::layout::Variant {
+                name: stringify!($variant),
+                payload: stringify!($payload),
+                size: std::mem::size_of::<$payload>(),
+            }),*],
+        )
+    }};
+}
+
+pub(crate) use {enum_layout, struct_layout};
+
+// Every lesson type, in tutorial order
+pub fn all() -> Vec<TypeLayout> {
+    let mut layouts = Vec::new();
+    layouts.extend(crate::lessons::tut4::layouts());
+    layouts.extend(crate::lessons::tut5::layouts());
+    layouts.extend(crate::lessons::tut7::layouts());
+    layouts.extend(crate::lessons::tut9::layouts());
+    layouts.extend(crate::lessons::tut11::layouts());
+    layouts
+}
+
+// One line per type, e.g. "tut7::Point  12  4  no"
+pub fn summary(layouts: &[TypeLayout]) -> String {
+    let width = layouts
+        .iter()
+        .map(|layout| layout.name.len())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+    let mut table = format!(
+        "{:<width$}  {:>5}  {:>5}  heap\n",
+        "type",
+        "size",
+        "align",
+        width = width
+    );
+    for layout in layouts {
+        writeln!(
+            table,
+            "{:<width$}  {:>5}  {:>5}  {}",
+            layout.name,
+            layout.size,
+            layout.align,
+            yes_no(layout.owns_heap()),
+            width = width
+        )
+        .unwrap();
+    }
+    table
+}
@@ -0,0 +1,23 @@
+// The tutorials
+//
+// One module per tutorial, each with its lessons and a `register` function that adds them to the
+// registry. The lessons keep unused bindings and non-idiomatic snippets on purpose to show syntax,
+// so their lints are silenced once here for all of them instead of cluttering every lesson.
+
+#![allow(
+    unused,
+    unreachable_patterns,
+    mismatched_lifetime_syntaxes,
+    clippy::all
+)]
+
+pub mod tut10;
+pub mod tut11;
+pub mod tut2;
+pub mod tut3;
+pub mod tut4;
+pub mod tut5;
+pub mod tut6;
+pub mod tut7;
+pub mod tut8;
+pub mod tut9;
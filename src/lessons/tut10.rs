@@ -0,0 +1,249 @@
+// Understanding Rust Hash Maps
+
+use crate::{
+    alloc_counter,
+    interner::{Interner, Symbol},
+    lessons::tut5,
+    registry::{LessonInfo, Registry},
+};
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+};
+
+// A hash map is used as key-value storage.
+pub fn understanding_hash_map(out: &mut dyn Write) -> io::Result<()> {
+    // You need to specify the type of key-value pairs that
+    // will be stored in the hash map.
+    let mut map: HashMap<i32, String> = HashMap::new();
+
+    // Inserting values into the map.
+    map.insert(0, "script_saga_follower_one".to_string());
+    map.insert(1, "script_saga_follower_two".to_string());
+    map.insert(2, "script_saga_follower_three".to_string());
+
+    // Getting values from the map.
+    let value_one = map.get(&0); // Returns an Option because a value may or may not exist.
+
+    // Getting the length of the map.
+    let length = map.len();
+
+    // You can also create a map using capacity and get the capacity.
+    let map_with_capacity: HashMap<i32, String> = HashMap::with_capacity(10);
+    let capacity = map_with_capacity.capacity(); // With capacity, you tell the compiler to reserve memory for that amount of elements
+                                                 // without reallocating the memory.
+
+    // Iterating over a map.
+    for (key, value) in map.iter() {
+        // Execute code for key and value.
+    }
+
+    // Iterating over keys of a map.
+    for key in map.keys() {
+        // Execute code with keys.
+    }
+
+    // Iterating over values of a map.
+    for v in map.values() {
+        // Execute code for values.
+    }
+
+    // Other useful functions.
+
+    // Convert into a Vec.
+    let a: Vec<&i32> = map.keys().collect(); // Collect is an iterator function that collects all the elements and converts
+                                             // them into an array of the specified type.
+
+    // All the iterator functions are valid. For example, if you want to filter out.
+    let filter: Vec<(&i32, &String)> = map
+        .iter()
+        .filter(|&(_, value)| value == &"script_saga_follower_one".to_string())
+        .collect(); // Will discuss more in detail about the iterator trait later on.
+
+    for (key, value) in filter.iter() {
+        writeln!(out, "{}", value)?;
+    }
+
+    // Removing a value.
+    map.remove(&0);
+
+    // Check whether the key exists in the map.
+    let contains = map.contains_key(&0);
+
+    // Check if the map is empty.
+    let is_empty = map.is_empty();
+
+    // Cleans the map but keeps the allocated memory for reuse.
+    map.clear();
+
+    Ok(())
+}
+
+// Interning the names of the map
+
+// The values above are three different Strings, and inserting the same name a thousand times would
+// make a thousand copies of it. An interner stores each distinct name once and hands out a Symbol,
+// so the map keeps small ids and the names are looked up when they're needed. The interner belongs
+// to this lesson and to tut5::InternedFollower, the other followers of the tutorials keep Strings.
+pub fn understanding_interning(out: &mut dyn Write) -> io::Result<()> {
+    let names = [
+        "script_saga_follower_one",
+        "script_saga_follower_two",
+        "script_saga_follower_three",
+    ];
+
+    let mut interner = Interner::new();
+    let mut map: HashMap<i32, Symbol> = HashMap::new();
+    for key in 0..3000 {
+        map.insert(key, interner.intern(names[key as usize % names.len()]));
+    }
+
+    // Same name, same symbol
+    writeln!(out, "{} keys, {} distinct names", map.len(), interner.len())?;
+    writeln!(
+        out,
+        "key 0 and key 3 share {}: {}",
+        map[&0],
+        map[&0] == map[&3]
+    )?;
+    // resolve borrows the name from the interner, no copy is made
+    writeln!(out, "key 1 is {}", interner.resolve(map[&1]))?;
+
+    // Storing the names as Strings would keep a copy for every key
+    let copied: usize = map
+        .values()
+        .map(|&symbol| interner.resolve(symbol).len())
+        .sum();
+    writeln!(
+        out,
+        "bytes of names: {} copied, {} interned",
+        copied,
+        interner.bytes()
+    )?;
+
+    // get only looks, it never adds a name
+    writeln!(
+        out,
+        "get(\"script_saga_follower_two\"): {:?}",
+        interner.get("script_saga_follower_two")
+    )?;
+    writeln!(out, "get(\"ferris\"): {:?}", interner.get("ferris"))?;
+
+    // The structs of tut5 can share the names too
+    let followers: Vec<tut5::InternedFollower> = (0..1000)
+        .map(|index| {
+            tut5::InternedFollower::new(
+                &mut interner,
+                names[index % names.len()],
+                "saga",
+                123456789,
+                91,
+            )
+        })
+        .collect();
+    writeln!(
+        out,
+        "{} followers, {} distinct names, first is {} {}",
+        followers.len(),
+        interner.len(),
+        followers[0].first_name(&interner),
+        followers[0].last_name(&interner)
+    )?;
+
+    // Interning a name that's already there doesn't allocate
+    if alloc_counter::counted_section(out)? {
+        let (_, stats) = alloc_counter::measure(|| {
+            for name in names.iter().cycle().take(1000) {
+                interner.intern(name);
+            }
+        });
+        writeln!(
+            out,
+            "interning 1000 known names allocates: {}",
+            !stats.is_empty()
+        )?;
+        let (copies, stats) = alloc_counter::measure(|| {
+            names
+                .iter()
+                .cycle()
+                .take(1000)
+                .map(|name| name.to_string())
+                .collect::<Vec<String>>()
+        });
+        writeln!(
+            out,
+            "copying them into Strings, an allocation per name: {}",
+            stats.allocations >= copies.len()
+        )?;
+    }
+
+    Ok(())
+}
+
+// Understanding Rust Strings
+
+pub fn understanding_rust_strings(out: &mut dyn Write) -> io::Result<()> {
+    // There are two types of strings in Rust:
+    // - Static strings that are stored in the program binary.
+    // - Dynamic strings that are stored in heap memory.
+    // When I say static string, I mean a type of string whose size is known at compile time,
+    // and when I say dynamic string, I mean a type of string whose size is unknown at compile time.
+
+    let static_str: &str = "script_saga"; // Static strings are always of reference type because they are a slice of elements that are stored
+                                          // in the program binary.
+
+    let dynamic_str = String::new(); // Dynamic string.
+
+    // There are multiple ways of creating a dynamic string.
+    let dynamic_str = "script_saga".to_string();
+    let dynamic_str = String::from("script_saga");
+    let dynamic_str = String::new();
+
+    // Converting a String to &str type.
+    let str_type = &dynamic_str[..]; // Slicing.
+
+    check_deref_coercion(&dynamic_str); // Here you can see the function input is of &str type, but you can give String type
+                                        // because String type implements a deref trait and specifies what to do when a type is referenced,
+                                        // and as a String implements a deref trait, it converts to &str automatically.
+
+    // Deref trait is a special type of trait that tells the Rust compiler what to do when a type is referenced because in Rust,
+    // dereferencing happens automatically.
+
+    writeln!(out, "{:?}", str_type)?;
+
+    Ok(())
+}
+
+pub fn check_deref_coercion(arg_one: &str) {}
+
+// Lessons of this tutorial, used by the lesson runner
+
+pub fn register(registry: &mut Registry) {
+    registry.register(LessonInfo {
+        id: "hash_map",
+        title: "Understanding hash maps",
+        tutorial: 10,
+        tags: &["hash map", "collections", "iterators"],
+        entry_name: "understanding_hash_map",
+        entry: understanding_hash_map,
+        diagrams: &["hash map.png"],
+    });
+    registry.register(LessonInfo {
+        id: "interning",
+        title: "Interning strings",
+        tutorial: 10,
+        tags: &["interning", "hash map", "symbols", "strings"],
+        entry_name: "understanding_interning",
+        entry: understanding_interning,
+        diagrams: &["hash map.png"],
+    });
+    registry.register(LessonInfo {
+        id: "strings",
+        title: "Understanding strings",
+        tutorial: 10,
+        tags: &["strings", "str", "deref coercion"],
+        entry_name: "understanding_rust_strings",
+        entry: understanding_rust_strings,
+        diagrams: &["rust string.png"],
+    });
+}
@@ -0,0 +1,234 @@
+// Understanding file I/O and io::ErrorKind
+
+use crate::{
+    layout::{enum_layout, TypeLayout},
+    registry::{LessonInfo, Registry},
+};
+use std::{
+    env,
+    fs::{self, OpenOptions},
+    io::{self, ErrorKind, Write},
+    path::{Path, PathBuf},
+    process,
+};
+
+// Every function in std::fs returns io::Result<T>, which is Result<T, io::Error>.
+// An io::Error tells you what went wrong through error.kind(), an ErrorKind enum with variants like
+// NotFound, PermissionDenied and AlreadyExists. Matching on the kind lets you recover differently
+// depending on what happened, instead of treating every failure the same way.
+
+// Where the lesson with this name plays, under the system temp directory.
+// The process id keeps two runs at the same time from stepping on each other.
+pub fn lesson_dir_path(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("practice_one-{}-{}", name, process::id()))
+}
+
+// A fresh, empty directory at lesson_dir_path for the lesson to play in
+pub fn lesson_dir(name: &str) -> io::Result<PathBuf> {
+    let dir = lesson_dir_path(name);
+    match fs::remove_dir_all(&dir) {
+        Ok(()) => {}
+        // Nothing to clean up is fine, any other error is not
+        Err(error) if error.kind() == ErrorKind::NotFound => {}
+        Err(error) => return Err(error),
+    }
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+// Reading, writing, appending and listing
+
+// fs::write creates the file or replaces everything in it
+pub fn write_file(path: &Path, text: &str) -> io::Result<()> {
+    fs::write(path, text)
+}
+
+// For appending you need OpenOptions, append(true) moves every write to the end of the file
+// and create(true) creates the file when it isn't there yet
+pub fn append_line(path: &Path, line: &str) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", line)
+}
+
+pub fn read_file(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path)
+}
+
+// fs::read_dir gives an iterator of io::Result<DirEntry>, every entry can fail on its own
+pub fn list_files(dir: &Path) -> io::Result<Vec<String>> {
+    let mut names = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_file() {
+            names.push(entry.file_name().to_string_lossy().into_owned());
+        }
+    }
+    // read_dir doesn't promise any order
+    names.sort();
+    Ok(names)
+}
+
+// Recovering depending on the ErrorKind
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Recovery {
+    // The file isn't there yet, start from default contents
+    CreateWithDefault,
+    // Someone already made the file, keep what they wrote
+    KeepExisting,
+    // We can't write here, write somewhere we can
+    WriteElsewhere,
+    // Anything else is a real problem, give the error to the caller
+    GiveUp,
+}
+
+pub fn recovery_for(error: &io::Error) -> Recovery {
+    match error.kind() {
+        ErrorKind::NotFound => Recovery::CreateWithDefault,
+        ErrorKind::AlreadyExists => Recovery::KeepExisting,
+        ErrorKind::PermissionDenied => Recovery::WriteElsewhere,
+        _ => Recovery::GiveUp,
+    }
+}
+
+// NotFound: a missing file is created with the default contents
+pub fn read_or_create(path: &Path, default: &str) -> io::Result<String> {
+    match fs::read_to_string(path) {
+        Ok(text) => Ok(text),
+        Err(error) if recovery_for(&error) == Recovery::CreateWithDefault => {
+            fs::write(path, default)?;
+            Ok(default.to_string())
+        }
+        Err(error) => Err(error),
+    }
+}
+
+// AlreadyExists: create_new(true) refuses to overwrite a file, which is how you avoid clobbering
+// someone else's work. Returns whether the file was created.
+pub fn create_or_keep(path: &Path, text: &str) -> io::Result<bool> {
+    match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(mut file) => {
+            file.write_all(text.as_bytes())?;
+            Ok(true)
+        }
+        Err(error) if recovery_for(&error) == Recovery::KeepExisting => Ok(false),
+        Err(error) => Err(error),
+    }
+}
+
+// PermissionDenied: when the file can't be written, the text goes to the fallback path instead.
+// Returns the path that was written.
+pub fn save_or_fallback(path: &Path, fallback: &Path, text: &str) -> io::Result<PathBuf> {
+    match fs::write(path, text) {
+        Ok(()) => Ok(path.to_path_buf()),
+        Err(error) if recovery_for(&error) == Recovery::WriteElsewhere => {
+            fs::write(fallback, text)?;
+            Ok(fallback.to_path_buf())
+        }
+        Err(error) => Err(error),
+    }
+}
+
+// Permissions are platform specific: unix has mode bits, elsewhere there's only a read-only flag
+#[cfg(unix)]
+pub fn set_writable(path: &Path, writable: bool) -> io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    let mode = if writable { 0o755 } else { 0o555 };
+    fs::set_permissions(path, fs::Permissions::from_mode(mode))
+}
+
+#[cfg(not(unix))]
+pub fn set_writable(path: &Path, writable: bool) -> io::Result<()> {
+    let mut permissions = fs::metadata(path)?.permissions();
+    permissions.set_readonly(!writable);
+    fs::set_permissions(path, permissions)
+}
+
+fn show_file_io(dir: &Path, out: &mut dyn Write) -> io::Result<()> {
+    let notes = dir.join("notes.txt");
+
+    write_file(&notes, "first line\n")?;
+    append_line(&notes, "second line")?;
+    append_line(&notes, "third line")?;
+    writeln!(out, "notes.txt contains:")?;
+    for line in read_file(&notes)?.lines() {
+        writeln!(out, "    {}", line)?;
+    }
+
+    // NotFound
+    let settings = read_or_create(&dir.join("settings.txt"), "theme=dark")?;
+    writeln!(
+        out,
+        "NotFound: settings.txt was missing, created it with `{}`",
+        settings
+    )?;
+
+    // AlreadyExists
+    let created = create_or_keep(&notes, "this would replace the notes")?;
+    writeln!(out, "AlreadyExists: created a new notes.txt? {}", created)?;
+    writeln!(
+        out,
+        "notes.txt still has {} lines",
+        read_file(&notes)?.lines().count()
+    )?;
+
+    writeln!(out, "files: {}", list_files(dir)?.join(", "))?;
+
+    // PermissionDenied
+    let locked = dir.join("locked");
+    fs::create_dir(&locked)?;
+    set_writable(&locked, false)?;
+    let error = fs::write(locked.join("notes.txt"), "text")
+        .err()
+        // root (and Administrator on Windows) can write anywhere, so there the error is made up
+        // to show what everyone else gets
+        .unwrap_or_else(|| io::Error::from(ErrorKind::PermissionDenied));
+    writeln!(out, "{:?} -> {:?}", error.kind(), recovery_for(&error))?;
+
+    // Any other kind, e.g. a file that isn't valid UTF-8 read as a String, is given up on
+    let binary = dir.join("binary.dat");
+    fs::write(&binary, [0xff, 0xfe])?;
+    let error = read_file(&binary).unwrap_err();
+    writeln!(out, "{:?} -> {:?}", error.kind(), recovery_for(&error))?;
+
+    Ok(())
+}
+
+pub fn understanding_file_io(out: &mut dyn Write) -> io::Result<()> {
+    let dir = lesson_dir("tut11")?;
+    let shown = show_file_io(&dir, out);
+
+    // Clean up even when the lesson failed half way, the locked directory has to be writable
+    // again before it can be removed
+    set_writable(&dir.join("locked"), true).ok();
+    fs::remove_dir_all(&dir).ok();
+    shown
+}
+
+// Recovery for `practice_one layout`: without payloads an enum is just its discriminant
+
+pub(crate) fn layouts() -> Vec<TypeLayout> {
+    vec![enum_layout!(
+        "tut11::Recovery",
+        Recovery {
+            CreateWithDefault: (),
+            KeepExisting: (),
+            WriteElsewhere: (),
+            GiveUp: (),
+        }
+    )]
+}
+
+// Lessons of this tutorial, used by the lesson runner
+
+pub fn register(registry: &mut Registry) {
+    registry.register(LessonInfo {
+        id: "file_io",
+        title: "Reading, writing and recovering from io errors",
+        tutorial: 11,
+        tags: &["files", "io", "ErrorKind", "error handling"],
+        entry_name: "understanding_file_io",
+        entry: understanding_file_io,
+        diagrams: &[],
+    });
+}
@@ -0,0 +1,233 @@
+// esult and Option are two special type of Enum that comes with the default std library
+// Result capture the error and correct value whereas Option is used to define some type and None
+
+/*
+    pub enum Result<T, E> {
+    Ok(T),
+    Err(E),
+    }
+
+    pub enum Option<T> {
+    None,
+    Some(T),
+    }
+    T and E are generic types dont worry about them right now will discuss it later
+*/
+
+// here is how you can use it with functions
+
+use crate::{
+    error::Error,
+    registry::{LessonInfo, Registry},
+};
+use std::io::{self, Write};
+
+// The error is the crate's Error enum instead of a String, so the caller can match on what kind
+// of failure it was, e.g. Err(Error::Validation(message)) => ...
+pub fn function_return_a_result() -> Result<i32, Error> {
+    // code goes here...
+
+    let condition = true;
+
+    if condition {
+        Ok(1)
+    } else {
+        Err(Error::Validation(String::from("condition is false")))
+    }
+}
+
+// here is how to use Option as return in function
+pub fn function_return_an_option() -> Option<i32> {
+    // code goes here...
+
+    let condition = true;
+
+    if condition {
+        Some(1)
+    } else {
+        None
+    }
+}
+
+// Option and Result combinators
+
+// Instead of matching on every Option or Result, you can chain methods that change the value inside
+// and deal with the failure only once, at the end. They are ordinary methods on the two enums.
+
+// map changes the value inside Ok and leaves an Err as it is
+pub fn doubled_result() -> Result<i32, Error> {
+    function_return_a_result().map(|value| value * 2)
+}
+
+// ok_or_else turns an Option into a Result, None becomes the error built by the closure.
+// The closure only runs when there is no value, ok_or would build the error every time.
+pub fn option_or_error() -> Result<i32, Error> {
+    function_return_an_option().ok_or_else(|| Error::NotFound {
+        what: "value",
+        name: "function_return_an_option".to_string(),
+        available: Vec::new(),
+    })
+}
+
+// and_then chains a step that can fail on its own. With map you would end up with
+// Result<Result<i32, Error>, Error> here.
+pub fn hundred_divided_by_result() -> Result<i32, Error> {
+    function_return_a_result().and_then(|value| {
+        100_i32
+            .checked_div(value)
+            .ok_or_else(|| Error::Validation(String::from("cannot divide by zero")))
+    })
+}
+
+// The ? operator returns the error to the caller right away, the rest of the function only runs
+// with a valid number
+pub fn parse_follower_count(text: &str) -> Result<i32, Error> {
+    let count: i32 = text.trim().parse().map_err(|error| {
+        Error::Validation(format!("`{}` is not a follower count: {}", text, error))
+    })?;
+
+    if count < 0 {
+        return Err(Error::Validation(format!(
+            "follower count {} is negative",
+            count
+        )));
+    }
+    Ok(count)
+}
+
+// transpose swaps Option<Result<T, E>> into Result<Option<T>, E>: no text at all is fine, text that
+// isn't a number is an error
+pub fn parse_optional_count(text: Option<&str>) -> Result<Option<i32>, Error> {
+    text.map(parse_follower_count).transpose()
+}
+
+// collect into Result<Vec<_>, _> stops at the first Err and returns it, otherwise you get every value
+pub fn parse_all_counts(texts: &[&str]) -> Result<Vec<i32>, Error> {
+    texts
+        .iter()
+        .map(|text| parse_follower_count(text))
+        .collect()
+}
+
+// An early return chain, every ? is a point where the function can stop with an error
+pub fn total_followers(texts: &[&str]) -> Result<i32, Error> {
+    let counts = parse_all_counts(texts)?;
+    let bonus = function_return_a_result()?;
+    let first_follower = option_or_error()?;
+
+    counts
+        .iter()
+        .try_fold(bonus + first_follower, |total, count| {
+            total.checked_add(*count)
+        })
+        .ok_or_else(|| Error::Validation(String::from("too many followers to count")))
+}
+
+pub fn understanding_combinators(out: &mut dyn Write) -> io::Result<()> {
+    let doubled = doubled_result();
+    writeln!(out, "map: {:?}", doubled)?;
+    assert!(matches!(doubled, Ok(2)));
+
+    let from_option = option_or_error();
+    writeln!(out, "ok_or_else: {:?}", from_option)?;
+    assert!(matches!(from_option, Ok(1)));
+
+    // None only turns into an error when there's nothing inside
+    let missing: Option<i32> = None;
+    let missing = missing.ok_or_else(|| Error::Validation(String::from("no value")));
+    writeln!(out, "ok_or_else on None: {:?}", missing)?;
+    assert!(missing.is_err());
+
+    let divided = hundred_divided_by_result();
+    writeln!(out, "and_then: {:?}", divided)?;
+    assert!(matches!(divided, Ok(100)));
+
+    // The Option versions work the same way
+    let option_chain = function_return_an_option()
+        .map(|value| value + 1)
+        .and_then(|value| value.checked_mul(10));
+    writeln!(out, "option map + and_then: {:?}", option_chain)?;
+    assert_eq!(option_chain, Some(20));
+
+    let no_text = parse_optional_count(None);
+    let good_text = parse_optional_count(Some("42"));
+    let bad_text = parse_optional_count(Some("many"));
+    writeln!(out, "transpose: {:?} {:?}", no_text, good_text)?;
+    writeln!(out, "transpose with bad text: {:?}", bad_text)?;
+    assert!(matches!(no_text, Ok(None)));
+    assert!(matches!(good_text, Ok(Some(42))));
+    assert!(bad_text.is_err());
+
+    Ok(())
+}
+
+pub fn understanding_error_propagation(out: &mut dyn Write) -> io::Result<()> {
+    let all = parse_all_counts(&["1", "2", "3"]);
+    writeln!(out, "collect: {:?}", all)?;
+    assert!(matches!(all.as_deref(), Ok([1, 2, 3])));
+
+    // The first bad entry decides the error, the entries after it are never parsed
+    let with_bad_entry = parse_all_counts(&["1", "-2", "three"]);
+    writeln!(out, "collect with bad entries: {:?}", with_bad_entry)?;
+    assert!(
+        matches!(with_bad_entry, Err(Error::Validation(message)) if message.contains("negative"))
+    );
+
+    let total = total_followers(&["10", "20"]);
+    writeln!(out, "? chain: {:?}", total)?;
+    assert!(matches!(total, Ok(32)));
+
+    let overflow = total_followers(&["2147483647"]);
+    writeln!(out, "? chain that overflows: {:?}", overflow)?;
+    assert!(overflow.is_err());
+
+    Ok(())
+}
+
+// Lessons of this tutorial, used by the lesson runner
+
+pub fn register(registry: &mut Registry) {
+    registry.register(LessonInfo {
+        id: "result",
+        title: "Returning a Result from a function",
+        tutorial: 2,
+        tags: &["result", "error handling", "enum"],
+        entry_name: "function_return_a_result",
+        entry: |out| writeln!(out, "{:?}", function_return_a_result()),
+        diagrams: &[],
+    });
+    registry.register(LessonInfo {
+        id: "option",
+        title: "Returning an Option from a function",
+        tutorial: 2,
+        tags: &["option", "enum"],
+        entry_name: "function_return_an_option",
+        entry: |out| writeln!(out, "{:?}", function_return_an_option()),
+        diagrams: &[],
+    });
+    registry.register(LessonInfo {
+        id: "combinators",
+        title: "Option and Result combinators",
+        tutorial: 2,
+        tags: &[
+            "option",
+            "result",
+            "map",
+            "and_then",
+            "ok_or_else",
+            "transpose",
+        ],
+        entry_name: "understanding_combinators",
+        entry: understanding_combinators,
+        diagrams: &[],
+    });
+    registry.register(LessonInfo {
+        id: "propagation",
+        title: "Propagating errors with ? and collect",
+        tutorial: 2,
+        tags: &["result", "error handling", "?", "collect", "early return"],
+        entry_name: "understanding_error_propagation",
+        entry: understanding_error_propagation,
+        diagrams: &[],
+    });
+}
@@ -0,0 +1,373 @@
+// declaring varibles in rust
+
+use crate::{
+    alloc_counter, borrows, numeric,
+    registry::{LessonInfo, Registry},
+    source,
+    tracked::{Timeline, Tracked},
+};
+use std::io::{self, Write};
+
+pub fn variable_in_rust() {
+    // A variable can be used to store data or a pointer to a specific memory location in Rust.
+    // Variables can be defined and initialized using the 'let' or 'const' keyword.
+    // Variables are immutable by default and can be made mutable by adding the 'mut' keyword.
+    // (There is a smart pointer type that does not require explicit initialization with the 'mut' keyword, which will be discussed later.)
+
+    /* Important:
+        Rust behaves differently for data stored in stack memory and data stored in heap memory,
+        as specified by ownership and borrow rules, which we will explore later.
+    */
+
+    /*  PRO TIP:
+        Whenever you write code in any language, keep memory in mind. Imagine memory as an address used to store something,
+        like your home address. While most languages have garbage collectors, Rust uses an ownership model for memory management,
+        providing more control to developers than garbage collectors. Memory is easy; just don't overreact!
+        It will make things easier almost everywhere. 😅
+    */
+
+    let a: &str = "script_saga"; // &str is a string slice stored directly in the program binary
+    let a = "script_saga"; // You don't need to specify a type explicitly; the Rust compiler is smart enough. However, there are cases where you need to specify the type explicitly.
+
+    let a = b"script_saga"; // Redefining a variable with the same name is called shadowing, and it shadows the previous declaration.
+
+    let arg_bool = true;
+    let arg_int = 32; // Read the documentation for all integer types and choose according to the required memory.
+                      // The numbers lesson below and `practice_one explore-numbers` show the size and range of each one.
+
+    let arg_float = 69.9; // Read the documentation for all float types and choose according to the required memory.
+
+    let pointer_arg = &arg_bool; // A pointer to some memory.
+
+    const MY_CONSTANT: &'static str = "script_saga"; // Constants require the type to be explicitly defined, and the convention is to use capitalized letters.
+
+    let mut arg_mutable = false;
+    arg_mutable = true;
+
+    // Will discuss complex types in some other snapshot.
+}
+
+// Integer and float types
+
+/*
+    Integers come signed (i8, i16, i32, i64, i128, isize) and unsigned (u8 ... usize), floats as f32
+    and f64. The number is the size in bits; isize and usize are as big as a pointer on the target.
+    Without a suffix an integer literal is an i32 and a float literal an f64.
+
+    When a result doesn't fit its type, a plain `+` panics in debug builds and wraps around in
+    release builds. To choose the behaviour yourself use the methods:
+    checked_* returns None, wrapping_* wraps around, saturating_* stops at MIN or MAX and
+    overflowing_* returns the wrapped value with a bool saying it overflowed.
+
+    Run `practice_one explore-numbers` to try your own literals and operations.
+*/
+
+pub fn understanding_numbers(out: &mut dyn Write) -> io::Result<()> {
+    write!(out, "{}", numeric::range_table(false))?;
+    writeln!(
+        out,
+        "isize and usize are as big as a pointer, 32 or 64 bits depending on the target"
+    )?;
+
+    // Literals can be written in other bases, with underscores and with the type as a suffix
+    for line in [
+        "1_000_000",
+        "0xff",
+        "0b1010_1010u8",
+        "0o777",
+        "2.5e3f32",
+        "250u8 + 10",
+        "-128i8 - 1",
+        "100i8 * 2",
+        "-128i8 / -1",
+        "7u8 / 0",
+        "1.0 / 0.0",
+    ] {
+        writeln!(out)?;
+        match numeric::evaluate(line) {
+            Ok(answer) => write!(out, "{}", answer)?,
+            Err(error) => writeln!(out, "{}: {}", line, error)?,
+        }
+    }
+
+    // A literal that doesn't fit its type doesn't compile
+    match numeric::parse_literal("256u8") {
+        Ok(_) => {}
+        Err(error) => writeln!(out, "\n{}", error)?,
+    }
+
+    // Floats never overflow or panic, they end up infinite or NaN instead
+    writeln!(out)?;
+    for (expression, result) in numeric::float_edge_cases() {
+        writeln!(out, "{:<40}{}", expression, result)?;
+    }
+
+    Ok(())
+}
+
+// Understanding Rust ownership in a simpler way.
+
+/*
+    When a program is executed, it has two types of memory: stack and heap.
+    Working with stack memory is faster compared to heap memory because variables
+    are stored in memory at compile time in an ordered manner, whereas data in heap memory
+    is stored in an unordered manner. Therefore, the allocator needs to do some work to find a space
+    to store a particular data and keep a book tracking it. That's why pushing data into
+    stack memory is faster than allocating data in the heap.
+
+    Ownership rules:
+    1. Each value in Rust has an owner.
+    2. There can only be one owner at a time.
+    3. When the owner goes out of scope, the value will be dropped.
+
+    The data whose size can't be known at compile time is called dynamic data and is stored
+    in heap memory, while the data whose size can be known at compile time is stored in stack memory.
+    So when you reassign a variable to another variable, Rust first checks if the data is sized or unsized.
+    If it is sized, Rust simply copies and pushes it onto the stack. If it's unsized data,
+    Rust transfers the ownership to the new variable, and the other variable becomes invalid.
+
+    Ownership can be transferred to other variables, functions, as return values from functions, threads, closures,
+    and if you don't want to transfer ownership, you borrow. We'll look into that in the next segment.
+*/
+
+pub fn understand_ownership(out: &mut dyn Write) -> io::Result<()> {
+    let a: u32 = 4;
+    let b = a; // Its size is known at compile time, and hence it is copied and stored in the stack
+
+    let unsized_data = String::from("ScriptSaga");
+    let get_ownership = unsized_data; // This is unsized data, and then the ownership is transferred to a new variable, and unsized data is not valid anymore
+                                      // Using unsized_data after this line fails with E0382, see tests/compile-fail/tut3_use_after_move.rs
+
+    // A move doesn't print anything, so here are the same steps again with every String wrapped in
+    // Tracked, which writes down when a value is created, cloned, moved and dropped.
+    let timeline = Timeline::new();
+    {
+        let unsized_data = Tracked::new(
+            &timeline,
+            "unsized_data",
+            "understand_ownership",
+            String::from("ScriptSaga"),
+        );
+        let get_ownership = unsized_data.moved_to("get_ownership", "understand_ownership");
+
+        // A clone is a second value with its own owner, the original stays valid
+        let copy = get_ownership.clone_as("copy");
+
+        // Passing a value to a function moves it into the function, which drops it when it returns
+        take_ownership(copy.moved_to("arg", "take_ownership"));
+
+        {
+            let inner = Tracked::new(&timeline, "inner", "inner block", String::from("temporary"));
+        } // inner goes out of scope and is dropped right here
+
+        // Returning a value moves it out of the function to the caller
+        let returned = give_ownership(&timeline);
+    } // Still owned values are dropped at the end of their scope, in reverse order of declaration
+
+    timeline.write(out)
+}
+
+fn take_ownership(arg: Tracked<String>) {
+    // arg is owned by this function now
+}
+
+fn give_ownership(timeline: &Timeline) -> Tracked<String> {
+    let follower = Tracked::new(
+        timeline,
+        "follower",
+        "give_ownership",
+        String::from("follower"),
+    );
+    follower.moved_to("returned", "understand_ownership")
+}
+
+// Counting what goes on the heap
+
+/*
+    The practice_one binary counts every heap allocation (see alloc_counter.rs), so the claims
+    above can be checked: sized data is copied on the stack without touching the heap, a String
+    asks the allocator for space, a move only copies the pointer, length and capacity on the stack,
+    and a clone or a drop go back to the allocator.
+*/
+
+pub fn understand_heap_allocations(out: &mut dyn Write) -> io::Result<()> {
+    if !alloc_counter::counted_section(out)? {
+        return Ok(());
+    }
+
+    let (b, stats) = alloc_counter::measure(|| {
+        let a: u32 = 4;
+        let b = a;
+        b
+    });
+    writeln!(out, "copying a u32:        {}", stats.counts())?;
+
+    let (literal, stats) = alloc_counter::measure(|| "ScriptSaga");
+    writeln!(out, "a &str literal:       {}", stats.counts())?; // It lives in the binary itself
+
+    // How many bytes the allocator hands out is up to std, but always enough for the text
+    let (unsized_data, stats) = alloc_counter::measure(|| String::from("ScriptSaga"));
+    writeln!(out, "String::from:         {}", stats.counts())?;
+    writeln!(
+        out,
+        "    room for the {} bytes of the text: {}",
+        unsized_data.len(),
+        stats.bytes_allocated >= unsized_data.len()
+    )?;
+
+    let (get_ownership, stats) = alloc_counter::measure(move || {
+        let get_ownership = unsized_data;
+        get_ownership
+    });
+    writeln!(out, "moving the String:    {}", stats.counts())?;
+
+    let (copy, stats) = alloc_counter::measure(|| get_ownership.clone());
+    writeln!(out, "cloning the String:   {}", stats.counts())?;
+
+    let ((), stats) = alloc_counter::measure(move || drop(copy));
+    writeln!(out, "dropping the clone:   {}", stats.counts())?;
+
+    Ok(())
+}
+
+// Understanding Rust Borrow Rules in Simpler Words
+
+/*
+    In simpler terms, borrowing in Rust simply means getting a reference to a variable.
+    There are some borrow rules defined in Rust, but before that, just remember one
+    thing very carefully:
+
+    "The scope of a variable is defined from the line where the variable is first declared
+    until it is no longer used. If it is not used, the scope is defined until the end. Also, the scope
+    remains within a { } block, and if any value or variable is used inside a { },
+    like
+    {
+        <some_variable_or_value> this value is always returned from the scope
+    }
+    "
+
+    There are some borrow rules that the Rust borrow checker uses to prevent race conditions,
+    as follows:
+
+    1. At any given time, you can have either one mutable reference or any number of immutable references.
+    2. References must always be valid.
+*/
+
+pub fn understand_borrow_rules(out: &mut dyn Write) -> io::Result<()> {
+    // Why do we borrow? -> We borrow when we don't want to transfer ownership.
+    let mut arg1: String = String::from("follow_script_saga");
+
+    // Multiple immutable borrows are allowed, which simply means you want to read the data.
+    let immutable_borrow_arg1_first = &arg1;
+    let immutable_borrow_arg1_second = &arg1;
+
+    // Uncommenting the line below will give an error because the variable is borrowed
+    // both mutably and immutably in the same scope of immutable_borrow_arg1_first.
+    // let mutable_borrow_arg1 = &mut arg1;
+    // (tests/compile-fail/tut3_mutable_and_immutable_borrow.rs checks that this really fails with E0502)
+
+    writeln!(out, "{}", immutable_borrow_arg1_first)?;
+
+    // Mutable borrowing is valid here because immutable_borrow_arg1_first is not in scope now.
+    let mutable_borrow_arg1 = &mut arg1;
+
+    // A borrowed reference can be given to a function as an argument or can be returned from a function,
+    // but it requires an understanding of lifetimes, which is beyond the scope of this example.
+
+    // Here are the same borrows drawn line by line: o is where a borrow starts, * where it is used
+    // and | where it is still live in between. After its last use a borrow is over, so the mutable
+    // borrow doesn't overlap the immutable ones.
+    let timeline =
+        borrows::analyse(&borrow_rules_snippet(false)).expect("the lesson is a valid snippet");
+    write!(out, "\n{}", timeline)?;
+
+    // And with the line above uncommented, the mutable borrow starts while `a` is still live
+    let timeline =
+        borrows::analyse(&borrow_rules_snippet(true)).expect("the lesson is a valid snippet");
+    write!(out, "\n{}", timeline)?;
+
+    Ok(())
+}
+
+// The borrows of understand_borrow_rules as a snippet for the timelines above and `practice_one
+// borrows`. It's read from the code of the lesson, so the two can't drift apart: the statements up
+// to the timelines, without comments and printing instead of writing to `out`. With `conflict` the
+// mutable borrow that is commented out is put back in.
+pub fn borrow_rules_snippet(conflict: bool) -> String {
+    let source = source::tutorial_source(3).unwrap_or_default();
+    let mut snippet = String::new();
+    for line in source
+        .lines()
+        .skip_while(|line| !line.starts_with("pub fn understand_borrow_rules("))
+        .skip(1)
+        .take_while(|line| !line.contains("Here are the same borrows drawn"))
+    {
+        let line = line.trim();
+        let line = match line.strip_prefix("// ") {
+            Some(code) if conflict && code == "let mutable_borrow_arg1 = &mut arg1;" => code,
+            _ => line,
+        };
+        if line.is_empty() || line.starts_with("//") {
+            continue;
+        }
+        let line = line
+            .replace("writeln!(out, ", "println!(")
+            .replace(")?;", ");");
+        snippet.push_str(&line);
+        snippet.push('\n');
+    }
+    snippet
+}
+
+// Lessons of this tutorial, used by the lesson runner
+
+pub fn register(registry: &mut Registry) {
+    registry.register(LessonInfo {
+        id: "variables",
+        title: "Declaring variables in Rust",
+        tutorial: 3,
+        tags: &["variables", "mutability", "shadowing", "constants"],
+        entry_name: "variable_in_rust",
+        entry: |_| {
+            variable_in_rust();
+            Ok(())
+        },
+        diagrams: &[],
+    });
+    registry.register(LessonInfo {
+        id: "numbers",
+        title: "Integer and float types",
+        tutorial: 3,
+        tags: &["integers", "floats", "overflow", "literals", "NaN"],
+        entry_name: "understanding_numbers",
+        entry: understanding_numbers,
+        diagrams: &[],
+    });
+    registry.register(LessonInfo {
+        id: "ownership",
+        title: "Understanding Rust ownership",
+        tutorial: 3,
+        tags: &["ownership", "stack", "heap", "move"],
+        entry_name: "understand_ownership",
+        entry: understand_ownership,
+        diagrams: &["1.png"],
+    });
+    registry.register(LessonInfo {
+        id: "heap",
+        title: "Counting heap allocations",
+        tutorial: 3,
+        tags: &["heap", "stack", "allocation", "move", "clone"],
+        entry_name: "understand_heap_allocations",
+        entry: understand_heap_allocations,
+        diagrams: &[],
+    });
+    registry.register(LessonInfo {
+        id: "borrow_rules",
+        title: "Understanding Rust borrow rules",
+        tutorial: 3,
+        tags: &["borrowing", "references", "ownership"],
+        entry_name: "understand_borrow_rules",
+        entry: understand_borrow_rules,
+        diagrams: &["2.png"],
+    });
+}
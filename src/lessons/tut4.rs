@@ -0,0 +1,405 @@
+// Understanding Rust Lifetimes
+
+/*
+   Rust lifetimes are a very simple concept. Just think of it this way: the lifetime of a variable is the scope within which
+   the variable is valid.
+
+   Since Rust variables can be referenced, the Rust compiler needs to know whether those references are valid or not,
+   because nobody likes dangling references. The Rust compiler helps a developer prevent dangling references using
+   a lifetime, as every reference in Rust requires a lifetime to be specified. The Rust compiler tries to assign
+   a lifetime to the required references by following lifetime elision rules.
+
+   The first rule is that the compiler assigns a lifetime parameter to each parameter that's a reference. In other words,
+   a function with one parameter gets one lifetime parameter: `fn foo<'a>(x: &'a i32);`, and a function with two parameters gets
+   two separate lifetime parameters: `fn foo<'a, 'b>(x: &'a i32, y: &'b i32);` and so on.
+
+   The second rule is that if there is exactly one input lifetime parameter, that lifetime is assigned to all output lifetime
+   parameters: `fn foo<'a>(x: &'a i32) -> &'a i32;`.
+
+   The third rule is that if there are multiple input lifetime parameters, but one of them is `&self` or `&mut self` because this
+   is a method, the lifetime of self is assigned to all output lifetime parameters. This third rule makes methods much nicer to
+   read and write because fewer symbols are necessary.
+
+   If, after following the elision rules, the Rust compiler doesn't able to figure out the lifetime of all the variables, then you need to explicitly define all the lifetimes.
+
+   As a general rule, you have to specify the lifetime wherever a reference is stored or transferred in or out from a function.
+*/
+
+use crate::{
+    alloc_counter,
+    arena::Arena,
+    followers::{Followers, OwnedFollower},
+    layout::{struct_layout, TypeLayout},
+    registry::{LessonInfo, Registry},
+};
+use std::{
+    cell::Cell,
+    io::{self, Write},
+};
+
+pub fn understanding_lifetime() {
+    // Lifetimes can be used everywhere when references are not present in the same scope.
+    // Simple example:
+    let a: i32 = 5;
+    let b = &a; // Here, you don't need to specify the lifetime because the Rust compiler already knows the scope of the above variables.
+}
+
+pub fn function_without_lifetime_reference(arg1: &i32, arg2: &bool) {
+    // In this function, the Rust compiler automatically assigns lifetimes to input variables,
+    // similar to function_without_lifetime_reference(arg1: &'a i32, arg2: &'b bool), following
+    // the lifetime elision rules.
+}
+
+pub fn function_with_lifetime_reference<'solana>(
+    arg1: &'solana i32,
+    arg2: &'solana bool,
+    arg3: &'solana mut String,
+) {
+    // You can give any name to your lifetime but follow the alphabetic convention
+}
+
+pub fn function_with_one_param(arg1: &str) -> &str {
+    // Follows the second rule, so its lifetime can be inferred by the compiler.
+    arg1
+}
+
+// pub fn function_with_incorrect_return_ref(arg1: &str, arg2: &bool, arg3: &str) -> &str {
+//     let a = "script_saga";
+//     // a
+//     &arg1
+//     // The above code is wrong because the reference is destroyed after the function execution,
+//     // so the Rust compiler gets confused. Here you need to specify the lifetime manually.
+//     // Always remember the returning lifetime will be the smallest of all lifetimes if lifetimes are not specified,
+//     // and hence the compiler gets confused.
+// }
+// (tests/compile-fail/tut4_incorrect_return_ref.rs checks that this fails with E0106)
+
+pub fn function_with_correct_return_ref<'a>(arg1: &'a str) -> &str {
+    arg1
+}
+
+// Sample example
+
+pub fn script_saga() {
+    let arg1 = "script";
+    {
+        let arg2 = "saga";
+        execute_life_time(&arg1, &arg2); // This is correct
+    }
+
+    // execute_life_time(&arg1, &arg2); // This is incorrect because both args don't have the same lifetime
+    // (tests/compile-fail/tut4_argument_out_of_scope.rs keeps the returned reference past the block
+    // and checks that this fails with E0597, arg2 does not live long enough)
+}
+
+pub fn execute_life_time<'a>(arg1: &'a str, arg2: &'a str) -> &'a str {
+    arg1
+}
+
+// Lifetimes in struct
+
+// Here you need to specify a lifetime
+// pub struct WrongScriptSagaFollower {
+//    first_name: &str,
+//    last_name: &str
+// }
+// (tests/compile-fail/tut4_wrong_struct_lifetime.rs checks that this fails with E0106)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptSagaFollower<'a> {
+    first_name: &'a str,
+    last_name: String,
+}
+
+impl<'a> ScriptSagaFollower<'a> {
+    pub fn method_with_self(self, arg1: i32, arg2: bool) {
+        // Here you don't need to specify the lifetime because it follows elision third rule.
+        // So the function will have this representation method_with_self<'a>(self, arg1: 'a i32, arg2: 'a bool)
+    }
+
+    pub fn method_with_self_return_ref<'b>(self, arg1: &'b str) -> &'a str {
+        self.first_name
+    }
+
+    // This is wrong; here you need to specify the lifetime of the return type, which should be the same as 'a.
+    // pub fn method_with_self_wrong_return_ref<'b>(self, arg1: &'b str) -> &str {
+    //     self.first_name
+    // }
+    // (see tests/compile-fail/tut4_method_wrong_return_ref.rs)
+
+    // Rest everything remains the same for associated functions that do not have a self reference.
+}
+
+// The fields are private, so code outside this module builds and reads the struct through these.
+// The follower can't outlive the string its first name is borrowed from.
+impl<'a> ScriptSagaFollower<'a> {
+    pub fn new(first_name: &'a str, last_name: String) -> Self {
+        ScriptSagaFollower {
+            first_name,
+            last_name,
+        }
+    }
+
+    pub fn first_name(&self) -> &'a str {
+        self.first_name
+    }
+
+    pub fn last_name(&self) -> &str {
+        &self.last_name
+    }
+}
+
+// Structs that borrow from a text
+
+/*
+    ScriptSagaFollower<'a> keeps its first name as &'a str, so it can point into a text somebody
+    else owns instead of copying the name out of it. Its last name is a String on purpose, the
+    struct shows a borrowed and an owned field side by side, and a String can't point into someone
+    else's text. So it can't be read out of a text without copying anything.
+
+    That's why followers.rs has a follower type of its own, followers::Follower<'a>, with both names
+    borrowed. Reading a list gives one per line, each a pair of pointers into the list, and 'a makes
+    sure the list stays alive as long as any follower still points into it. Nothing is copied until
+    a Follower is turned into a ScriptSagaFollower, which is no longer zero-copy: the first name is
+    still borrowed, the last name is copied into a new String.
+*/
+
+const FOLLOWER_LIST: &str = "# first name, last name
+script, saga
+ferris, crab
+  grace , hopper
+";
+
+pub fn understanding_zero_copy(out: &mut dyn Write) -> io::Result<()> {
+    // The text is owned here, every follower borrows from it
+    let text = String::from(FOLLOWER_LIST);
+    for follower in Followers::new(&text) {
+        match follower {
+            Ok(follower) => {
+                // The name points inside the text, nothing was copied
+                let borrowed = text
+                    .as_bytes()
+                    .as_ptr_range()
+                    .contains(&follower.first_name.as_ptr());
+                writeln!(
+                    out,
+                    "{} {}  (borrowed from the text: {})",
+                    follower.first_name, follower.last_name, borrowed
+                )?;
+            }
+            Err(error) => writeln!(out, "error: {}", error)?,
+        }
+    }
+    // Dropping text while a follower is still used afterwards fails with E0505, the followers would
+    // point at freed memory
+
+    // A bad line says where the problem is, and the next lines are still read
+    for follower in Followers::new("script, saga\nferris\nada, , lovelace\n, crab") {
+        if let Err(error) = follower {
+            writeln!(out, "error: {}", error)?;
+        }
+    }
+
+    // Counting the allocations shows the difference with copying every name into a String
+    if alloc_counter::counted_section(out)? {
+        let (count, stats) =
+            alloc_counter::measure(|| Followers::new(&text).filter(Result::is_ok).count());
+        writeln!(
+            out,
+            "borrowing {} followers allocates: {}",
+            count,
+            !stats.is_empty()
+        )?;
+        let (_, stats) = alloc_counter::measure(|| {
+            Followers::new(&text)
+                .filter_map(Result::ok)
+                .map(ScriptSagaFollower::from)
+                .count()
+        });
+        writeln!(
+            out,
+            "turned into ScriptSagaFollowers: {} allocation per follower, the last name is copied",
+            stats.allocations / count
+        )?;
+        let (_, stats) = alloc_counter::measure(|| {
+            Followers::new(&text)
+                .filter_map(Result::ok)
+                .map(OwnedFollower::from)
+                .count()
+        });
+        writeln!(
+            out,
+            "copying both names into Strings: {} allocations per follower",
+            stats.allocations / count
+        )?;
+    }
+
+    Ok(())
+}
+
+// Structs allocated in an arena
+
+/*
+    In script_saga above, arg2 lives in an inner scope, so a reference to it can't be used after that
+    scope ends. An arena changes where values live: arena.rs keeps everything allocated in it until
+    the arena itself is dropped, and every reference it hands out borrows the arena, not the scope the
+    value was created in. Allocate the names and the followers in arenas and all their references have
+    the same lifetime, the arena's, which is exactly the one 'a that execute_life_time<'a> asks for.
+*/
+
+// A follower and the fan it follows, both allocated in the same arena.
+// follows is a Cell so that two fans can follow each other once both exist.
+#[derive(Debug)]
+pub struct Fan<'a> {
+    pub follower: &'a ScriptSagaFollower<'a>,
+    pub follows: Cell<Option<&'a Fan<'a>>>,
+}
+
+// Every fan comes from one arena, so one lifetime covers the slice, the fans and their names
+pub fn name_of_followed<'a>(fans: &[&'a Fan<'a>], index: usize) -> Option<&'a str> {
+    let followed = fans.get(index)?.follows.get()?;
+    Some(followed.follower.first_name())
+}
+
+pub fn understanding_arenas(out: &mut dyn Write) -> io::Result<()> {
+    let names: Arena<String> = Arena::new();
+    let followers: Arena<ScriptSagaFollower<'_>> = Arena::new();
+    let fans: Arena<Fan<'_>> = Arena::new();
+
+    let script = followers.alloc(ScriptSagaFollower::new(
+        names.alloc("script".to_string()),
+        "saga".to_string(),
+    ));
+    let ferris;
+    {
+        // Created in an inner scope, but the names and the follower belong to the arenas
+        let first_name = names.alloc(String::from("ferris"));
+        ferris = followers.alloc(ScriptSagaFollower::new(first_name, "crab".to_string()));
+    }
+    // Both first names are &'a str borrowed from the names arena, so this compiles unlike the
+    // commented out call in script_saga
+    let chosen = execute_life_time(script.first_name(), ferris.first_name());
+    writeln!(out, "execute_life_time picked {}", chosen)?;
+
+    // Fans pointing at each other, a cycle plain references can't build on the stack
+    let script_fan = fans.alloc(Fan {
+        follower: script,
+        follows: Cell::new(None),
+    });
+    let ferris_fan = fans.alloc(Fan {
+        follower: ferris,
+        follows: Cell::new(Some(script_fan)),
+    });
+    script_fan.follows.set(Some(ferris_fan));
+    let all = [&*script_fan, &*ferris_fan];
+    for index in 0..all.len() {
+        writeln!(
+            out,
+            "{} follows {}",
+            all[index].follower.first_name(),
+            name_of_followed(&all, index).unwrap_or("nobody")
+        )?;
+    }
+
+    // Allocating in bulk fills a few big chunks instead of one allocation per follower
+    let bulk = followers
+        .alloc_extend((0..1000).map(|_| ScriptSagaFollower::new("script", "saga".to_string())));
+    writeln!(
+        out,
+        "{} followers in {} chunks, {} in the bulk slice",
+        followers.len(),
+        followers.chunks(),
+        bulk.len()
+    )?;
+
+    // The last names are made up front, so only the followers themselves are counted. Boxing needs
+    // an allocation per follower, the arena one per chunk.
+    if alloc_counter::counted_section(out)? {
+        let last_names = || (0..1000).map(|_| "saga".to_string()).collect::<Vec<_>>();
+        let arena = Arena::new();
+        let names = last_names();
+        let (_, in_arena) = alloc_counter::measure(|| {
+            for last_name in names {
+                arena.alloc(ScriptSagaFollower::new("script", last_name));
+            }
+        });
+        let names = last_names();
+        let (boxed, one_by_one) = alloc_counter::measure(|| {
+            names
+                .into_iter()
+                .map(|last_name| Box::new(ScriptSagaFollower::new("script", last_name)))
+                .collect::<Vec<_>>()
+        });
+        writeln!(
+            out,
+            "1000 boxed followers, one allocation each: {}",
+            one_by_one.allocations >= boxed.len()
+        )?;
+        writeln!(
+            out,
+            "1000 followers in {} arena chunks, fewer than 20 allocations: {}",
+            arena.chunks(),
+            in_arena.allocations < 20
+        )?;
+    }
+
+    Ok(())
+}
+
+// The follower as `practice_one layout` shows it. The &str first name is a pointer and a length
+// into someone else's text, the String last name adds a capacity and owns its bytes on the heap.
+
+pub(crate) fn layouts() -> Vec<TypeLayout> {
+    vec![
+        struct_layout!("tut4::ScriptSagaFollower", ScriptSagaFollower<'static> {
+            first_name: &'static str,
+            last_name: String,
+        }),
+    ]
+}
+
+// Lessons of this tutorial, used by the lesson runner
+
+pub fn register(registry: &mut Registry) {
+    registry.register(LessonInfo {
+        id: "lifetime",
+        title: "Understanding Rust lifetimes",
+        tutorial: 4,
+        tags: &["lifetimes", "elision", "references"],
+        entry_name: "understanding_lifetime",
+        entry: |_| {
+            understanding_lifetime();
+            Ok(())
+        },
+        diagrams: &["3.png"],
+    });
+    registry.register(LessonInfo {
+        id: "script_saga",
+        title: "Lifetimes across scopes",
+        tutorial: 4,
+        tags: &["lifetimes", "functions", "references"],
+        entry_name: "script_saga",
+        entry: |_| {
+            script_saga();
+            Ok(())
+        },
+        diagrams: &["4.png", "struct lifetime.png"],
+    });
+    registry.register(LessonInfo {
+        id: "zero_copy",
+        title: "Structs that borrow from a text",
+        tutorial: 4,
+        tags: &["lifetimes", "structs", "zero copy", "parsing"],
+        entry_name: "understanding_zero_copy",
+        entry: understanding_zero_copy,
+        diagrams: &["struct lifetime.png"],
+    });
+    registry.register(LessonInfo {
+        id: "arena",
+        title: "Structs allocated in an arena",
+        tutorial: 4,
+        tags: &["lifetimes", "structs", "arena", "allocation"],
+        entry_name: "understanding_arenas",
+        entry: understanding_arenas,
+        diagrams: &["struct lifetime.png"],
+    });
+}
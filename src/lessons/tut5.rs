@@ -0,0 +1,283 @@
+// Understanding Structure in Rust
+
+/*
+    A struct is used to store multiple different types of data in one place.
+    In Rust, a struct can be used with a custom type or as a class object if you want to
+    implement object-oriented programming.
+*/
+
+use crate::{
+    interner::{Interner, Symbol},
+    layout::{enum_layout, struct_layout, TypeLayout},
+    registry::{LessonInfo, Registry},
+};
+use std::io::{self, Write};
+
+// Normal struct implementation
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptSagaFollower {
+    first_name: String,
+    last_name: String,
+    mobile_number: u64,
+    country_code: u16,
+}
+
+// The fields are private, so code outside this module builds and reads the struct through these
+impl ScriptSagaFollower {
+    pub fn new(
+        first_name: String,
+        last_name: String,
+        mobile_number: u64,
+        country_code: u16,
+    ) -> Self {
+        ScriptSagaFollower {
+            first_name,
+            last_name,
+            mobile_number,
+            country_code,
+        }
+    }
+
+    pub fn first_name(&self) -> &str {
+        &self.first_name
+    }
+
+    pub fn last_name(&self) -> &str {
+        &self.last_name
+    }
+
+    pub fn mobile_number(&self) -> u64 {
+        self.mobile_number
+    }
+
+    pub fn country_code(&self) -> u16 {
+        self.country_code
+    }
+}
+
+// The same follower with its names interned. Thousands of followers called
+// "script_saga_follower_one" all share the one copy kept by the interner, and each name field is a
+// 4 byte Symbol instead of a 24 byte String. Reading a name needs the interner, and the &str it
+// gives can't outlive it.
+//
+// It sits next to ScriptSagaFollower, it doesn't replace it: the lessons of this tutorial keep the
+// String follower, intern and to_follower convert between the two, and the interning lesson of
+// tut10 is the one that uses it.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InternedFollower {
+    first_name: Symbol,
+    last_name: Symbol,
+    mobile_number: u64,
+    country_code: u16,
+}
+
+impl InternedFollower {
+    pub fn new(
+        interner: &mut Interner,
+        first_name: &str,
+        last_name: &str,
+        mobile_number: u64,
+        country_code: u16,
+    ) -> Self {
+        InternedFollower {
+            first_name: interner.intern(first_name),
+            last_name: interner.intern(last_name),
+            mobile_number,
+            country_code,
+        }
+    }
+
+    pub fn first_name<'i>(&self, interner: &'i Interner) -> &'i str {
+        interner.resolve(self.first_name)
+    }
+
+    pub fn last_name<'i>(&self, interner: &'i Interner) -> &'i str {
+        interner.resolve(self.last_name)
+    }
+
+    pub fn first_name_symbol(&self) -> Symbol {
+        self.first_name
+    }
+
+    pub fn last_name_symbol(&self) -> Symbol {
+        self.last_name
+    }
+
+    pub fn mobile_number(&self) -> u64 {
+        self.mobile_number
+    }
+
+    pub fn country_code(&self) -> u16 {
+        self.country_code
+    }
+
+    // Back to a follower owning its names, copying them out of the interner
+    pub fn to_follower(&self, interner: &Interner) -> ScriptSagaFollower {
+        ScriptSagaFollower::new(
+            self.first_name(interner).to_string(),
+            self.last_name(interner).to_string(),
+            self.mobile_number,
+            self.country_code,
+        )
+    }
+}
+
+impl ScriptSagaFollower {
+    pub fn intern(&self, interner: &mut Interner) -> InternedFollower {
+        InternedFollower::new(
+            interner,
+            &self.first_name,
+            &self.last_name,
+            self.mobile_number,
+            self.country_code,
+        )
+    }
+}
+
+// Pointer struct
+// Pointer structs can be used as custom types that require the same arguments, but you need to differentiate them because they
+// have different traits or implementations or when you want to be very specific.
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CartesianCoordinate(pub i32, pub i32, pub i32); // Implement some different types of functions or traits
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EulerCoordinate(pub i32, pub i32, pub i32); // Implement some different types of functions or traits
+
+pub fn implementing_rust_struct(out: &mut dyn Write) -> io::Result<()> {
+    // Tuple struct
+    let robot_cartesian_coordinate = CartesianCoordinate(0, 0, 0);
+    let robot_euler_coordinate = EulerCoordinate(10, 20, 30);
+
+    let user: ScriptSagaFollower = ScriptSagaFollower {
+        first_name: String::from(""),
+        last_name: String::from(""),
+        mobile_number: 123456789,
+        country_code: 91,
+    };
+
+    writeln!(out, "User first name: {}", user.first_name)?;
+    writeln!(out, "User last name: {}", user.last_name)?;
+    writeln!(out, "User mobile number: {}", user.mobile_number)?;
+    writeln!(out, "User country code: {}", user.country_code)?;
+
+    // create struct from other structs
+
+    let user_2: ScriptSagaFollower = ScriptSagaFollower {
+        first_name: "".to_string(),
+        ..user
+    };
+
+    Ok(())
+}
+
+// Understanding Enums in Rust
+
+// Enums in Rust are basic and simple, accessed via the :: notation.
+// "derive" is a procedural macro, which we will discuss in some other snippet. For now, understand that
+// it is required to implement the Debug trait to log enums and structs.
+// Enums are primarily used in pattern matching.
+#[derive(Debug)]
+pub enum ScriptSagaEnum {
+    SimpleVariant,
+    PointStructVariant(i32, i32, i32),
+    StructVariant {
+        first_name: String,
+        last_name: String,
+    },
+    SingleArgs(String),
+}
+
+pub fn understanding_rust_enums(out: &mut dyn Write) -> io::Result<()> {
+    let my_enum_simple_variant = ScriptSagaEnum::SimpleVariant;
+    let my_enum_point_variant = ScriptSagaEnum::PointStructVariant(32, 32, 32);
+    let my_enum_struct_variant = ScriptSagaEnum::StructVariant {
+        first_name: "script".to_string(),
+        last_name: "saga".to_string(),
+    };
+    let my_enum_single_args = ScriptSagaEnum::SingleArgs("".to_string());
+
+    writeln!(
+        out,
+        "{:?} {:?} {:?} {:?}",
+        my_enum_simple_variant, my_enum_point_variant, my_enum_struct_variant, my_enum_single_args
+    )?;
+
+    Ok(())
+}
+
+// Table for `practice_one layout`, next to the String follower the interned one shows how much
+// smaller a Symbol is
+
+pub(crate) fn layouts() -> Vec<TypeLayout> {
+    vec![
+        struct_layout!(
+            "tut5::ScriptSagaFollower",
+            ScriptSagaFollower {
+                first_name: String,
+                last_name: String,
+                mobile_number: u64,
+                country_code: u16,
+            }
+        ),
+        struct_layout!(
+            "tut5::InternedFollower",
+            InternedFollower {
+                first_name: Symbol,
+                last_name: Symbol,
+                mobile_number: u64,
+                country_code: u16,
+            }
+        ),
+        struct_layout!(
+            "tut5::CartesianCoordinate",
+            CartesianCoordinate {
+                0: i32,
+                1: i32,
+                2: i32
+            }
+        ),
+        struct_layout!(
+            "tut5::EulerCoordinate",
+            EulerCoordinate {
+                0: i32,
+                1: i32,
+                2: i32
+            }
+        ),
+        enum_layout!(
+            "tut5::ScriptSagaEnum",
+            ScriptSagaEnum {
+                SimpleVariant: (),
+                PointStructVariant: (i32, i32, i32),
+                StructVariant: (String, String),
+                SingleArgs: String,
+            }
+        ),
+    ]
+}
+
+// Lessons of this tutorial, used by the lesson runner
+
+pub fn register(registry: &mut Registry) {
+    registry.register(LessonInfo {
+        id: "structs",
+        title: "Understanding structs",
+        tutorial: 5,
+        tags: &["structs", "tuple structs", "struct update"],
+        entry_name: "implementing_rust_struct",
+        entry: implementing_rust_struct,
+        diagrams: &["rust struct.png", "creating struct from other struct.png"],
+    });
+    registry.register(LessonInfo {
+        id: "enums",
+        title: "Understanding enums",
+        tutorial: 5,
+        tags: &["enums", "derive", "debug"],
+        entry_name: "understanding_rust_enums",
+        entry: understanding_rust_enums,
+        diagrams: &["enum rust.png"],
+    });
+}
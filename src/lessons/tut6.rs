@@ -1,5 +1,8 @@
 // Understanding control flow in Rust
 
+use crate::registry::{LessonInfo, Registry};
+use std::io::{self, Write};
+
 pub fn understanding_if_else() {
     // if-else statements are used when you want to execute a certain functionality that depends
     // on some condition
@@ -41,29 +44,30 @@ pub fn understanding_if_else() {
        undefined, or empty values, here in Rust, the if condition can only work with boolean condition inputs.
 
        This is wrong => if None { some condition }
+       (tests/compile-fail/tut6_if_none.rs checks that this fails with E0308)
     */
 }
 
 // understanding loops in rust
 
-pub fn understanding_loops() {
+pub fn understanding_loops(out: &mut dyn Write) -> io::Result<()> {
     // This type of for loop is used as a range-based for loop where the last index of <start_index>..<last_index>
     // is exclusive.
     for i in 0..10 {
-        println!("working on index {}", i);
+        writeln!(out, "working on index {}", i)?;
     }
 
     // Conventional while loop which runs a block until a condition becomes false.
     let mut x = 0;
     while x < 100 {
-        print!("value of x {}", x);
+        write!(out, "value of x {}", x)?;
         x += 1;
     }
 
     let a: [i32; 5] = [1, 2, 3, 4, 5];
 
     for number in a {
-        println!("getting the value, not reference {}", number);
+        writeln!(out, "getting the value, not reference {}", number)?;
     }
 
     // Types that implement an Iterator trait need to define the next function to get the next value.
@@ -71,7 +75,35 @@ pub fn understanding_loops() {
     // Iterator is a way to iterate over any type of collection.
     // There are a few benefits of iterators which we discuss in some other snap.
     for number in a.iter() {
-        println!("current value reference is {}", number); // Remember in Rust, dereferences happen automatically for types that
-                                                           // implement a Deref trait.
+        writeln!(out, "current value reference is {}", number)?; // Remember in Rust, dereferences happen automatically for types that
+                                                                 // implement a Deref trait.
     }
+
+    Ok(())
+}
+
+// Lessons of this tutorial, used by the lesson runner
+
+pub fn register(registry: &mut Registry) {
+    registry.register(LessonInfo {
+        id: "if_else",
+        title: "Control flow with if-else",
+        tutorial: 6,
+        tags: &["control flow", "if", "else"],
+        entry_name: "understanding_if_else",
+        entry: |_| {
+            understanding_if_else();
+            Ok(())
+        },
+        diagrams: &["if else.png"],
+    });
+    registry.register(LessonInfo {
+        id: "loops",
+        title: "Understanding loops",
+        tutorial: 6,
+        tags: &["control flow", "loops", "iterators"],
+        entry_name: "understanding_loops",
+        entry: understanding_loops,
+        diagrams: &["rust for loops.png"],
+    });
 }
@@ -1,9 +1,18 @@
 // understanding pattern matching
 
-use std::{array, fmt::Error, vec};
+use crate::{
+    error::Error,
+    layout::{struct_layout, TypeLayout},
+    registry::{LessonInfo, Registry},
+};
+use std::{
+    array,
+    io::{self, Write},
+    vec,
+};
 
 // A VERY IMPORTANT THING IN RUST
-pub fn understanding_pattern_matching() {
+pub fn understanding_pattern_matching(out: &mut dyn Write) -> io::Result<()> {
     // Pattern matching is done via match expressions in Rust.
     // Pattern matching on enum variants.
 
@@ -60,7 +69,15 @@ pub fn understanding_pattern_matching() {
         }
     };
 
-    println!("value is {}", value);
+    writeln!(out, "value is {}", value)?;
+
+    // With false the function returns an Err instead of panicking, so the error arm runs and you
+    // can also match on the kind of error.
+    match something_that_returns_result_type(false) {
+        Ok(value) => writeln!(out, "value is {}", value)?,
+        Err(Error::Validation(reason)) => writeln!(out, "invalid input: {}", reason)?,
+        Err(error) => writeln!(out, "something else went wrong: {}", error)?,
+    }
 
     // You can match multiple expressions as well.
     let statement_one: i32 = 0;
@@ -80,14 +97,17 @@ pub fn understanding_pattern_matching() {
 
     if let Some(val) = value {
         // Execute some code using val
-        println!("{}", val);
+        writeln!(out, "{}", val)?;
     }
+
+    Ok(())
 }
 
+// Returns the failure as a value instead of panicking, so the caller decides what to do with it.
+// A panic here would crash whoever called it, no matter how carefully they match on the Result.
 pub fn something_that_returns_result_type(some_condition: bool) -> Result<i32, Error> {
     if !some_condition {
-        panic!("fmt error");
-        // return Err(0);
+        return Err(Error::Validation(String::from("some_condition is false")));
     }
     Ok(1)
 }
@@ -115,11 +135,13 @@ pub fn something_that_returns_result_type(some_condition: bool) -> Result<i32, E
 
 // Rust destructuring and pattern matching
 
-pub struct Point(i32, i32, i32);
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point(pub i32, pub i32, pub i32);
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Coordinate {
-    x: i32,
-    y: i32,
-    z: i32,
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
 }
 
 pub fn understanding_rust_destructuring_with_pattern_matching() {
@@ -128,7 +150,7 @@ pub fn understanding_rust_destructuring_with_pattern_matching() {
     let (a, mut b, c) = (1, 2, arr_1);
 
     // You cannot destructure an array as shown above.
-    // let (a, b) = arr_1;  // This is incorrect.
+    // let (a, b) = arr_1;  // This is incorrect, see tests/compile-fail/tut7_array_as_tuple.rs
     // Instead, you can use the range operator.
     let [a, ..] = arr_1; // This is correct.
 
@@ -143,7 +165,7 @@ pub fn understanding_rust_destructuring_with_pattern_matching() {
     // You need to specify all the fields while destructuring or use _ or .. syntax.
     // Use .. syntax for struct named identifiers {Key Value Struct} and use _ for unnamed identifiers (Point Struct),
     // else the compiler will complain.
-    // let Coordinate {x} = coordinate_one; This is wrong.
+    // let Coordinate {x} = coordinate_one; This is wrong, see tests/compile-fail/tut7_missing_struct_fields.rs
 
     let a = vec![1, 2, 3, 4];
 
@@ -175,3 +197,55 @@ pub fn understanding_rust_destructuring_with_pattern_matching() {
         }
     }
 }
+
+// Point and Coordinate for `practice_one layout`, three i32 in a row without padding
+
+pub(crate) fn layouts() -> Vec<TypeLayout> {
+    vec![
+        struct_layout!(
+            "tut7::Point",
+            Point {
+                0: i32,
+                1: i32,
+                2: i32
+            }
+        ),
+        struct_layout!(
+            "tut7::Coordinate",
+            Coordinate {
+                x: i32,
+                y: i32,
+                z: i32
+            }
+        ),
+    ]
+}
+
+// Lessons of this tutorial, used by the lesson runner
+
+pub fn register(registry: &mut Registry) {
+    registry.register(LessonInfo {
+        id: "pattern_matching",
+        title: "Understanding pattern matching",
+        tutorial: 7,
+        tags: &["pattern matching", "match", "if let"],
+        entry_name: "understanding_pattern_matching",
+        entry: understanding_pattern_matching,
+        diagrams: &["match statement.png", "match if let.png"],
+    });
+    registry.register(LessonInfo {
+        id: "destructuring",
+        title: "Destructuring with pattern matching",
+        tutorial: 7,
+        tags: &["pattern matching", "destructuring", "refutability"],
+        entry_name: "understanding_rust_destructuring_with_pattern_matching",
+        entry: |_| {
+            understanding_rust_destructuring_with_pattern_matching();
+            Ok(())
+        },
+        diagrams: &[
+            "rust destructing and patter matching.png",
+            "irrefutable and irrefutable pattern.png",
+        ],
+    });
+}
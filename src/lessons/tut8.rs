@@ -1,7 +1,9 @@
 // Understanding advanced pattern matching
 
-pub fn understanding_advanced_pattern_matching() {
+use crate::registry::{LessonInfo, Registry};
+use std::io::{self, Write};
 
+pub fn understanding_advanced_pattern_matching(out: &mut dyn Write) -> io::Result<()> {
     let number = 0;
 
     // Remember, match expressions are exhaustive and require the sll the conditions that the value can take to be specified.
@@ -10,15 +12,15 @@ pub fn understanding_advanced_pattern_matching() {
 
     match number {
         0..=10 => {
-            println!("Value is in between the range 1 and 10");
+            writeln!(out, "Value is in between the range 1 and 10")?;
         }
 
         11..=20 => {
-            println!("Value is in between the range 10 and 20");
+            writeln!(out, "Value is in between the range 10 and 20")?;
         }
 
         21 | 22 | 23 => {
-            println!("Value is either 21, 22, or 23");
+            writeln!(out, "Value is either 21, 22, or 23")?;
         }
 
         _ => {
@@ -26,7 +28,6 @@ pub fn understanding_advanced_pattern_matching() {
         }
     }
 
-
     let number = 0;
     // Match expression with match guards
     match number {
@@ -77,7 +78,11 @@ pub fn understanding_advanced_pattern_matching() {
             follower_count: count @ 1..=10,
         } => {
             // If you want to give an alias name to follower_count and have a pattern matched againt it
-            println!("Follower count is {} and is less between 1 to 10", count)
+            writeln!(
+                out,
+                "Follower count is {} and is less between 1 to 10",
+                count
+            )?;
         }
         ScriptSagaFollower::FOLLOWER {
             follower_count: 11..=20,
@@ -85,13 +90,34 @@ pub fn understanding_advanced_pattern_matching() {
             // If you want to do pattern matching inside the nested struct but don't care about the variable
             // Remember if you do pattern matching like this, the follower_count variable will not be available
             // outside like in the third arm
-            println!("Follower count is less between 11 to 20");
+            writeln!(out, "Follower count is less between 11 to 20")?;
         }
         ScriptSagaFollower::FOLLOWER { follower_count } if follower_count > 20 => {
-            println!("Follower count is more than 20")
+            writeln!(out, "Follower count is more than 20")?;
         }
         _ => {
             // Execute default code in which none of the above arms matches
         }
     }
+
+    Ok(())
+}
+
+// Lessons of this tutorial, used by the lesson runner
+
+pub fn register(registry: &mut Registry) {
+    registry.register(LessonInfo {
+        id: "advanced_pattern_matching",
+        title: "Advanced pattern matching",
+        tutorial: 8,
+        tags: &["pattern matching", "ranges", "match guards", "@ bindings"],
+        entry_name: "understanding_advanced_pattern_matching",
+        entry: understanding_advanced_pattern_matching,
+        diagrams: &[
+            "range based pattern matching.png",
+            "match gaurds.png",
+            "struct match guard.png",
+            "@ operator.png",
+        ],
+    });
 }
@@ -0,0 +1,269 @@
+// Rust Inheritance and Method Resolution
+
+/*
+    There is no concept of inheritance in Rust, but to implement OOP in Rust,
+    we have structs, implementations, and traits.
+*/
+
+use crate::{
+    alloc_counter,
+    layout::{struct_layout, TypeLayout},
+    registry::{LessonInfo, Registry},
+};
+use std::{
+    collections::{btree_map::Values, HashMap},
+    io::{self, Write},
+    vec,
+};
+
+pub trait Role {
+    // For traits, you don't need to define 'pub' in front of the function because
+    // in traits, all methods take access modifiers of the trait itself.
+
+    fn get_role_with_no_return_or_block(&self);
+    fn get_role_with_no_block(&self) -> ();
+    fn get_role_return_and_block(&self) -> () {
+        // This type of implementation means that this function is optional.
+        // This code block will execute by default if this is not overridden.
+    }
+}
+
+pub trait Pilot {
+    fn fly(&self, out: &mut dyn Write) -> io::Result<()>;
+}
+
+pub trait Wizard {
+    fn fly(&self, out: &mut dyn Write) -> io::Result<()>;
+}
+
+pub struct Human;
+
+impl Human {
+    pub fn fly(&self, out: &mut dyn Write) -> io::Result<()> {
+        writeln!(out, "Human flying")
+    }
+}
+
+impl Pilot for Human {
+    fn fly(&self, out: &mut dyn Write) -> io::Result<()> {
+        writeln!(out, "Pilot flying")
+    }
+}
+
+impl Wizard for Human {
+    fn fly(&self, out: &mut dyn Write) -> io::Result<()> {
+        writeln!(out, "Wizard flying")
+    }
+}
+
+// Human has the same 'fly' method coming from three different implementations.
+
+pub fn understanding_rust_inheritance(out: &mut dyn Write) -> io::Result<()> {
+    let human = Human;
+    human.fly(out)?; // Run the direct implementation
+    Pilot::fly(&human, out)?;
+    Wizard::fly(&human, out)?;
+
+    // Or you can use fully qualified syntax
+    <Human as Wizard>::fly(&human, out)?;
+
+    Ok(())
+}
+
+// Rust Vectors
+
+// Rust vectors are a special data type, essentially smart pointers, but you don't need to understand smart pointers right now.
+// Vectors are a special type of array that doesn't require a static size because elements are stored in heap memory, and their
+// reference is stored in the stack.
+
+// We use vectors when the size of the array is unknown at compile time.
+
+pub fn understanding_rust_vec(out: &mut dyn Write) -> io::Result<()> {
+    // Vectors can be defined in two ways
+    let mut vec_one: Vec<i32> = Vec::new(); // Using the module
+    let vec_two: Vec<bool> = vec![]; // Using the macro
+                                     // The type of a vector can be inferred directly from the first element in the vector, otherwise, it needs to be specified.
+
+    // Pushing values into the vector
+    vec_one.push(0);
+    vec_one.push(1);
+    vec_one.push(2);
+
+    // Checking if a vector is empty
+    let is_empty = vec_one.is_empty();
+    writeln!(out, "Is empty: {}", is_empty)?;
+
+    // Getting an element from the array using indexing
+    writeln!(out, "{}", vec_one[0])?;
+    let value_at_zero_index = vec_one.get(0); // Returns an Option type
+
+    // Getting the length of the vector
+    writeln!(out, "Length: {}", vec_one.len())?;
+
+    // Iterating over the array elements by converting the vector into a slice
+    for element in vec_one.as_slice() {
+        // Iterating over elements using a slice
+    }
+
+    // Iterating over the array using indexing
+    for index in 0..vec_one.len() {
+        // Iterating over an array using indexing
+    }
+
+    // Iterating over the vector using the iterator
+    for element in vec_one.iter() {
+        // 'element' is a reference and will be automatically dereferenced into i32 because of deref coercion
+    }
+
+    // Get the iterator for a vector
+    let mut vec_two_iter = vec_two.into_iter();
+    vec_two_iter.next();
+
+    // Additional Vec useful functions
+
+    vec_one.capacity(); // The number of elements an array can store without reallocating
+    vec_one.reverse();
+    vec_one.sort();
+    vec_one.insert(0, 1);
+    let does_contain = 1;
+    vec_one.contains(&does_contain);
+    let array_sliced = &vec_one[..];
+
+    let new_slice = [1, 2];
+    // Both sides must have the same length or these functions panic, so copy into a sub-slice of the vector
+    vec_one[..new_slice.len()].copy_from_slice(&new_slice); // It will copy elements from another slice
+    vec_one[..new_slice.len()].clone_from_slice(&new_slice); // Clone elements from another slice
+
+    // There are other functions that you can explore
+
+    // Popping the last value
+    vec_one.pop(); // Gives you an Option type
+
+    // You can use it with a while loop to iterate over the array until the Option value is None
+    while let Some(value) = vec_one.pop() {}
+
+    // You can also use it in an if statement
+    if let Some(value) = vec_one.pop() {}
+
+    Ok(())
+}
+
+// How a vector grows
+
+// A vector starts without any heap memory. When a push doesn't fit in the capacity, the vector asks
+// the allocator for a bigger block (about twice the size) and the elements are moved there, a
+// reallocation. If you know how many elements are coming, with_capacity allocates once up front.
+// The practice_one binary counts the allocations (see alloc_counter.rs), so here they are.
+
+pub fn understanding_vec_growth(out: &mut dyn Write) -> io::Result<()> {
+    if !alloc_counter::counted_section(out)? {
+        return Ok(());
+    }
+
+    let (mut followers, stats) = alloc_counter::measure(Vec::<i32>::new);
+    writeln!(out, "Vec::new: {}", stats.counts())?;
+
+    // How far the capacity grows on each reallocation is up to std and has changed between Rust
+    // versions, so only what always holds is printed
+    let mut first_push_allocated = false;
+    let mut reallocations = 0;
+    for follower in 0..10 {
+        let ((), stats) = alloc_counter::measure(|| followers.push(follower));
+        if follower == 0 {
+            first_push_allocated = stats.allocations == 1;
+        }
+        reallocations += stats.reallocations;
+    }
+    writeln!(out, "the first push allocated: {}", first_push_allocated)?;
+    writeln!(
+        out,
+        "the other 9 pushes reallocated at least once: {}",
+        reallocations > 0
+    )?;
+    // A push that finds spare capacity doesn't go to the allocator at all
+    writeln!(
+        out,
+        "but fewer than 9 times, the capacity grows ahead of the length: {}",
+        reallocations < 9
+    )?;
+    writeln!(
+        out,
+        "capacity >= len after 10 pushes: {}",
+        followers.capacity() >= followers.len()
+    )?;
+
+    let (followers, stats) = alloc_counter::measure(|| {
+        let mut followers = Vec::with_capacity(10);
+        for follower in 0..10 {
+            followers.push(follower);
+        }
+        followers
+    });
+    writeln!(
+        out,
+        "Vec::with_capacity(10) and 10 pushes: {}",
+        stats.counts()
+    )?;
+
+    // HashMap works the same way
+    let (scores, stats) = alloc_counter::measure(HashMap::<&str, u32>::new);
+    writeln!(out, "HashMap::new: {}", stats.counts())?;
+
+    let (scores, stats) = alloc_counter::measure(|| {
+        let mut scores = HashMap::with_capacity(10);
+        for (index, name) in ["script", "saga", "rust"].into_iter().enumerate() {
+            scores.insert(name, index);
+        }
+        scores
+    });
+    // The bytes depend on how HashMap stores its table, so only the counts are shown
+    writeln!(
+        out,
+        "HashMap::with_capacity(10) and 3 inserts: {} allocation, {} reallocations",
+        stats.allocations, stats.reallocations
+    )?;
+
+    Ok(())
+}
+
+// Human has no fields, so `practice_one layout` shows it taking no space at all (a zero sized type)
+
+pub(crate) fn layouts() -> Vec<TypeLayout> {
+    vec![struct_layout!("tut9::Human", Human {})]
+}
+
+// Lessons of this tutorial, used by the lesson runner
+
+pub fn register(registry: &mut Registry) {
+    registry.register(LessonInfo {
+        id: "inheritance",
+        title: "Inheritance and method resolution",
+        tutorial: 9,
+        tags: &["traits", "oop", "fully qualified syntax"],
+        entry_name: "understanding_rust_inheritance",
+        entry: understanding_rust_inheritance,
+        diagrams: &["fully qualified syntax.png"],
+    });
+    registry.register(LessonInfo {
+        id: "vec",
+        title: "Understanding vectors",
+        tutorial: 9,
+        tags: &["vec", "collections", "iterators", "heap"],
+        entry_name: "understanding_rust_vec",
+        entry: understanding_rust_vec,
+        diagrams: &[
+            "rust vector part 1.png",
+            "array vec functions.png",
+            "array iterator.png",
+        ],
+    });
+    registry.register(LessonInfo {
+        id: "vec_growth",
+        title: "How vectors grow on the heap",
+        tutorial: 9,
+        tags: &["vec", "HashMap", "heap", "capacity", "allocation"],
+        entry_name: "understanding_vec_growth",
+        entry: understanding_vec_growth,
+        diagrams: &[],
+    });
+}
@@ -0,0 +1,45 @@
+// practice_one
+//
+// The Rust tutorials as a library. The code of every tutorial lives under `lessons`, e.g.
+// `practice_one::lessons::tut9::Human`, the registry describes the runnable lessons and the other
+// modules are the tools built on top of it:
+//
+// - docs, quiz, exercises, search and progress: Markdown export, quizzes, exercise checks,
+//   full-text search and the learner's progress file
+// - alloc_counter, arena, followers, interner, layout and tracked: the allocation counter, arena,
+//   zero-copy parsing, string interning, memory layout and drop tracking the lessons show
+// - borrows and numeric: the borrow visualiser and the numeric explorer
+// - error and retry: the error type of the crate and retry helpers for flaky operations
+//
+// The runner behind the practice_one binary, the interactive browser, HTML export, the
+// highlighter and the source reader are internal, only the entry points below are exported.
+
+pub mod alloc_counter;
+pub mod arena;
+pub mod borrows;
+pub mod docs;
+pub mod error;
+pub mod exercises;
+pub mod followers;
+pub(crate) mod highlight;
+pub(crate) mod html;
+pub(crate) mod interactive;
+pub mod interner;
+pub mod layout;
+pub mod lessons;
+pub mod numeric;
+pub mod progress;
+pub mod quiz;
+pub mod registry;
+pub mod retry;
+pub(crate) mod runner;
+pub mod search;
+pub(crate) mod source;
+pub mod tracked;
+
+pub use error::{Error, Result};
+pub use highlight::{escape_html, highlight_html, tokenize, Token, TokenKind};
+pub use interactive::run as browse;
+pub use registry::{Lesson, LessonInfo, Registry};
+pub use runner::run;
+pub use source::{explanation, function_line, segments, tutorial_source, Segment};
@@ -1,12 +1,11 @@
-mod tut2;
-mod tut3;
-mod tut4;
-mod tut5;
-mod tut6;
-mod tut7;
-mod tut8;
-mod tut9;
-mod tut10;
+use practice_one::error;
+use std::{env, process::ExitCode};
+
+// Count heap allocations so the lessons about the heap can show them
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: practice_one::alloc_counter::CountingAllocator =
+    practice_one::alloc_counter::CountingAllocator;
 
 // how do we define a function in rust ?
 // fn <function_name>(arg1:type,arg2:type,arg3:type...) -> type {
@@ -24,17 +23,15 @@ pub fn script_saga_function_one(_arg1: u32, _arg2: &String, _arg3: bool) -> (u32
     (23, true)
 }
 
-fn main() {
-    // tut2::function_return_a_result();
-    // tut3::variable_in_rust()
-    // tut3::understand_borrow_rules();
-    // tut6::understanding_loops();
-    // tut8::understanding_advanced_pattern_matching();
-    // tut9::understanding_rust_inheritance();
-    // tut9::understanding_rust_vec();
-    // tut10::understanding_hash_map();
-    tut10::understanding_rust_strings();
+fn main() -> ExitCode {
+    // Pick a lesson from the command line, e.g. `cargo run -- tut10 strings` or `cargo run -- list`.
+    let args: Vec<String> = env::args().skip(1).collect();
 
-    // let arg2 = String::from("Shashank");
-    // script_saga_function_one(1, &arg2, true);
+    match practice_one::run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprint!("{}", error::report(&error));
+            ExitCode::FAILURE
+        }
+    }
 }
@@ -0,0 +1,651 @@
+// Exploring the numeric types
+//
+// Every primitive integer and float type with its size and range, the four ways integer arithmetic
+// can handle overflow (checked, wrapping, saturating and overflowing), a parser for Rust number
+// literals like `0xff`, `1_000_000` or `32u8`, and the float values that surprise people (NaN,
+// infinities, epsilon).
+//
+// `evaluate` answers one line the way the `explore-numbers` command and its prompt do:
+//
+//     u8             the size and range of a type
+//     0b1010_1010u8  what a literal means and which types it fits in
+//     250u8 + 10     the result in every overflow mode
+
+use crate::error::{Error, Result};
+use std::{
+    fmt::Write as _,
+    io::{self, BufRead, Write},
+};
+
+macro_rules! numeric_types {
+    ($($variant:ident => $type:ident),* $(,)?) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum NumericType {
+            $($variant),*
+        }
+
+        impl NumericType {
+            pub const ALL: &'static [NumericType] = &[$(NumericType::$variant),*];
+
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(NumericType::$variant => stringify!($type)),*
+                }
+            }
+
+            pub fn size(self) -> usize {
+                match self {
+                    $(NumericType::$variant => std::mem::size_of::<$type>()),*
+                }
+            }
+
+            // The smallest value, for floats the most negative finite one. Debug writes the big
+            // floats with an exponent instead of 300 zeros.
+            pub fn min(self) -> String {
+                match self {
+                    $(NumericType::$variant => format!("{:?}", <$type>::MIN)),*
+                }
+            }
+
+            pub fn max(self) -> String {
+                match self {
+                    $(NumericType::$variant => format!("{:?}", <$type>::MAX)),*
+                }
+            }
+        }
+    };
+}
+
+numeric_types! {
+    I8 => i8,
+    I16 => i16,
+    I32 => i32,
+    I64 => i64,
+    I128 => i128,
+    Isize => isize,
+    U8 => u8,
+    U16 => u16,
+    U32 => u32,
+    U64 => u64,
+    U128 => u128,
+    Usize => usize,
+    F32 => f32,
+    F64 => f64,
+}
+
+impl NumericType {
+    pub fn from_name(name: &str) -> Option<NumericType> {
+        NumericType::ALL
+            .iter()
+            .copied()
+            .find(|ty| ty.name() == name)
+    }
+
+    pub fn is_float(self) -> bool {
+        matches!(self, NumericType::F32 | NumericType::F64)
+    }
+
+    pub fn is_signed(self) -> bool {
+        !self.is_float() && self.name().starts_with('i')
+    }
+
+    // "an i32" but "a u8"
+    fn with_article(self) -> String {
+        let article = if self.name().starts_with('u') {
+            "a"
+        } else {
+            "an"
+        };
+        format!("{} {}", article, self.name())
+    }
+
+    // isize and usize are as big as a pointer, so they depend on the target
+    pub fn is_pointer_sized(self) -> bool {
+        matches!(self, NumericType::Isize | NumericType::Usize)
+    }
+
+    // "u8  1 byte  0 to 255"
+    pub fn describe(self) -> String {
+        let bytes = if self.size() == 1 { "byte" } else { "bytes" };
+        format!(
+            "{:<6}{:>3} {:<6}{} to {}",
+            self.name(),
+            self.size(),
+            bytes,
+            self.min(),
+            self.max()
+        )
+    }
+}
+
+// The value of a literal. Integers keep their sign apart so both u128::MAX and i128::MIN fit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Number {
+    Integer { negative: bool, magnitude: u128 },
+    Float(f64),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Literal {
+    pub text: String,
+    pub value: Number,
+    pub radix: u32,
+    pub suffix: Option<NumericType>,
+}
+
+impl Literal {
+    // Without a suffix Rust picks i32 for integers and f64 for floats, unless the context needs
+    // another type
+    pub fn default_type(&self) -> NumericType {
+        match (self.suffix, self.value) {
+            (Some(suffix), _) => suffix,
+            (None, Number::Integer { .. }) => NumericType::I32,
+            (None, Number::Float(_)) => NumericType::F64,
+        }
+    }
+
+    pub fn fits(&self, ty: NumericType) -> bool {
+        match self.value {
+            Number::Integer { .. } if ty.is_float() => false,
+            Number::Integer { .. } => integer_fits(self, ty),
+            Number::Float(value) => match ty {
+                NumericType::F32 => (value as f32).is_finite(),
+                NumericType::F64 => true,
+                _ => false,
+            },
+        }
+    }
+
+    // The types this literal can be without a compile error
+    pub fn types(&self) -> Vec<NumericType> {
+        match self.suffix {
+            Some(suffix) => vec![suffix],
+            None => NumericType::ALL
+                .iter()
+                .copied()
+                .filter(|ty| self.fits(*ty))
+                .collect(),
+        }
+    }
+}
+
+fn integer_fits(literal: &Literal, ty: NumericType) -> bool {
+    macro_rules! fits {
+        ($($variant:ident => $type:ty),*) => {
+            match ty {
+                $(NumericType::$variant => integer::<$type>(literal).is_some(),)*
+                NumericType::F32 | NumericType::F64 => true,
+            }
+        };
+    }
+    fits!(I8 => i8, I16 => i16, I32 => i32, I64 => i64, I128 => i128, Isize => isize,
+        U8 => u8, U16 => u16, U32 => u32, U64 => u64, U128 => u128, Usize => usize)
+}
+
+// The literal as an integer type, or None when it's a float or out of range
+fn integer<T: TryFrom<u128> + TryFrom<i128>>(literal: &Literal) -> Option<T> {
+    match literal.value {
+        Number::Integer {
+            negative: false,
+            magnitude,
+        } => T::try_from(magnitude).ok(),
+        Number::Integer {
+            negative: true,
+            magnitude,
+        } => T::try_from(0i128.checked_sub_unsigned(magnitude)?).ok(),
+        Number::Float(_) => None,
+    }
+}
+
+fn out_of_range(text: &str, ty: NumericType) -> Error {
+    Error::Validation(format!(
+        "`{}` is out of range for {}, which holds {} to {}",
+        text,
+        ty.name(),
+        ty.min(),
+        ty.max()
+    ))
+}
+
+// Parses a literal the way the compiler does: an optional minus sign, a 0x, 0o or 0b prefix,
+// digits with any number of underscores, a fraction or exponent for floats and a type suffix
+pub fn parse_literal(text: &str) -> Result<Literal> {
+    let invalid = |reason: &str| Error::Validation(format!("`{}` {}", text, reason));
+
+    let (negative, rest) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+    let (radix, rest) = match rest.get(..2) {
+        Some("0x") => (16, &rest[2..]),
+        Some("0o") => (8, &rest[2..]),
+        Some("0b") => (2, &rest[2..]),
+        _ => (10, rest),
+    };
+    if rest.starts_with('_') && radix == 10 {
+        return Err(invalid(
+            "starts with an underscore, that's a name and not a number",
+        ));
+    }
+
+    // Hex digits include the f of f32, so floats only take a suffix in decimal
+    let (digits, suffix) = match NumericType::ALL
+        .iter()
+        .copied()
+        .filter(|ty| radix == 10 || !ty.is_float())
+        .find(|ty| rest.ends_with(ty.name()) && rest.len() > ty.name().len())
+    {
+        Some(ty) => (&rest[..rest.len() - ty.name().len()], Some(ty)),
+        None => (rest, None),
+    };
+
+    let digits: String = digits.chars().filter(|c| *c != '_').collect();
+    if digits.is_empty() {
+        return Err(invalid("has no digits"));
+    }
+
+    let is_float = radix == 10
+        && (digits.contains(['.', 'e', 'E']) || suffix.is_some_and(NumericType::is_float));
+    let value = if is_float {
+        if let Some(ty) = suffix.filter(|ty| !ty.is_float()) {
+            return Err(invalid(&format!(
+                "is a float, it can't have the integer suffix {}",
+                ty.name()
+            )));
+        }
+        let valid = digits.starts_with(|c: char| c.is_ascii_digit())
+            && digits
+                .chars()
+                .all(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-'));
+        let value: f64 = valid
+            .then(|| digits.parse().ok())
+            .flatten()
+            .ok_or_else(|| invalid("is not a valid float"))?;
+        Number::Float(if negative { -value } else { value })
+    } else {
+        let magnitude =
+            u128::from_str_radix(&digits, radix).map_err(|error| match error.kind() {
+                std::num::IntErrorKind::PosOverflow => invalid("is too big even for u128"),
+                _ => invalid(&format!("has a digit that isn't valid in base {}", radix)),
+            })?;
+        Number::Integer {
+            negative,
+            magnitude,
+        }
+    };
+
+    let literal = Literal {
+        text: text.to_string(),
+        value,
+        radix,
+        suffix,
+    };
+    match suffix {
+        Some(ty) if !literal.fits(ty) => Err(out_of_range(text, ty)),
+        _ => Ok(literal),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Op {
+    pub fn parse(text: &str) -> Option<Op> {
+        match text {
+            "+" => Some(Op::Add),
+            "-" => Some(Op::Sub),
+            "*" => Some(Op::Mul),
+            "/" => Some(Op::Div),
+            _ => None,
+        }
+    }
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Op::Add => "+",
+            Op::Sub => "-",
+            Op::Mul => "*",
+            Op::Div => "/",
+        }
+    }
+}
+
+// The result of an operation in every mode. A plain `a + b` panics on overflow in debug builds
+// and wraps in release builds, the methods behind these modes behave the same in both.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Integer {
+        // checked_add and friends: None when the result doesn't fit
+        checked: Option<String>,
+        // wrapping_add: the result modulo 2^bits, so it goes round from MAX to MIN
+        wrapping: String,
+        // saturating_add: stops at MIN or MAX
+        saturating: String,
+        // overflowing_add: the wrapped result and whether it overflowed
+        overflowing: String,
+    },
+    // Floats don't overflow, they become infinite or NaN
+    Float(String),
+}
+
+macro_rules! integer_outcome {
+    ($type:ty, $a:expr, $b:expr, $op:expr) => {{
+        let (a, b): ($type, $type) = ($a, $b);
+        if $op == Op::Div && b == 0 {
+            // Only checked_div survives a division by zero, all the other modes panic
+            let panics = "panics, attempt to divide by zero".to_string();
+            Outcome::Integer {
+                checked: None,
+                wrapping: panics.clone(),
+                saturating: panics.clone(),
+                overflowing: panics,
+            }
+        } else {
+            let (checked, wrapping, saturating, overflowing) = match $op {
+                Op::Add => (
+                    a.checked_add(b),
+                    a.wrapping_add(b),
+                    a.saturating_add(b),
+                    a.overflowing_add(b),
+                ),
+                Op::Sub => (
+                    a.checked_sub(b),
+                    a.wrapping_sub(b),
+                    a.saturating_sub(b),
+                    a.overflowing_sub(b),
+                ),
+                Op::Mul => (
+                    a.checked_mul(b),
+                    a.wrapping_mul(b),
+                    a.saturating_mul(b),
+                    a.overflowing_mul(b),
+                ),
+                Op::Div => (
+                    a.checked_div(b),
+                    a.wrapping_div(b),
+                    a.saturating_div(b),
+                    a.overflowing_div(b),
+                ),
+            };
+            Outcome::Integer {
+                checked: checked.map(|value| value.to_string()),
+                wrapping: wrapping.to_string(),
+                saturating: saturating.to_string(),
+                overflowing: format!("({}, {})", overflowing.0, overflowing.1),
+            }
+        }
+    }};
+}
+
+macro_rules! float_outcome {
+    ($type:ty, $a:expr, $b:expr, $op:expr) => {{
+        let (a, b): ($type, $type) = ($a, $b);
+        let result = match $op {
+            Op::Add => a + b,
+            Op::Sub => a - b,
+            Op::Mul => a * b,
+            Op::Div => a / b,
+        };
+        Outcome::Float(format!("{:?}", result))
+    }};
+}
+
+fn float_value(literal: &Literal) -> f64 {
+    match literal.value {
+        Number::Float(value) => value,
+        Number::Integer {
+            negative,
+            magnitude,
+        } => {
+            let value = magnitude as f64;
+            if negative {
+                -value
+            } else {
+                value
+            }
+        }
+    }
+}
+
+// `a op b` in type ty, both literals must fit in it
+pub fn arithmetic(ty: NumericType, a: &Literal, op: Op, b: &Literal) -> Result<Outcome> {
+    for literal in [a, b] {
+        if !ty.is_float() && matches!(literal.value, Number::Float(_)) {
+            return Err(Error::Validation(format!(
+                "`{}` is a float, it can't be used as {}",
+                literal.text,
+                ty.name()
+            )));
+        }
+        if ty.is_float() && matches!(literal.value, Number::Integer { .. }) {
+            return Err(Error::Validation(format!(
+                "`{}` is an integer, it can't be used as {}",
+                literal.text,
+                ty.name()
+            )));
+        }
+        if !literal.fits(ty) {
+            return Err(out_of_range(&literal.text, ty));
+        }
+    }
+
+    macro_rules! dispatch {
+        ($($variant:ident => $type:ty),*) => {
+            match ty {
+                $(NumericType::$variant => integer_outcome!(
+                    $type,
+                    integer(a).expect("checked above"),
+                    integer(b).expect("checked above"),
+                    op
+                ),)*
+                NumericType::F32 => {
+                    float_outcome!(f32, float_value(a) as f32, float_value(b) as f32, op)
+                }
+                NumericType::F64 => float_outcome!(f64, float_value(a), float_value(b), op),
+            }
+        };
+    }
+    Ok(
+        dispatch!(I8 => i8, I16 => i16, I32 => i32, I64 => i64, I128 => i128, Isize => isize,
+        U8 => u8, U16 => u16, U32 => u32, U64 => u64, U128 => u128, Usize => usize),
+    )
+}
+
+// Float values and comparisons that don't do what you'd guess, as (expression, result) pairs
+pub fn float_edge_cases() -> Vec<(&'static str, String)> {
+    // Two zeros, so the divisions below aren't a value divided by itself
+    let (zero, other_zero) = (0.0f64, 0.0f64);
+    let nan = f64::NAN;
+    let also_nan = zero / other_zero;
+
+    vec![
+        ("0.0 / 0.0", format!("{:?}", zero / other_zero)),
+        ("1.0 / 0.0", format!("{:?}", 1.0 / zero)),
+        ("-1.0 / 0.0", format!("{:?}", -1.0 / zero)),
+        ("NAN == NAN", (nan == also_nan).to_string()),
+        ("NAN.is_nan()", nan.is_nan().to_string()),
+        (
+            "NAN < 1.0 || NAN >= 1.0",
+            (nan < 1.0 || nan >= 1.0).to_string(),
+        ),
+        ("-0.0 == 0.0", (-zero == zero).to_string()),
+        ("f64::MAX * 2.0", format!("{:?}", f64::MAX * 2.0)),
+        ("f32::EPSILON", format!("{:e}", f32::EPSILON)),
+        ("f64::EPSILON", format!("{:e}", f64::EPSILON)),
+        ("f64::MIN_POSITIVE", format!("{:e}", f64::MIN_POSITIVE)),
+        ("0.1 + 0.2", format!("{:?}", 0.1 + 0.2)),
+        ("0.1 + 0.2 == 0.3", (0.1 + 0.2 == 0.3).to_string()),
+        (
+            "(0.1 + 0.2 - 0.3).abs() < f64::EPSILON",
+            ((0.1f64 + 0.2 - 0.3).abs() < f64::EPSILON).to_string(),
+        ),
+        ("16_777_217.0f32", format!("{:?}", 16_777_217.0f32)),
+        // Casts from float to integer saturate, and NaN becomes 0
+        ("300.0 as u8", (300.0f64 as u8).to_string()),
+        ("-1.0 as u8", (-1.0f64 as u8).to_string()),
+        ("NAN as u8", (nan as u8).to_string()),
+    ]
+}
+
+fn describe_literal(literal: &Literal) -> String {
+    let mut text = String::new();
+    match literal.value {
+        Number::Integer {
+            negative,
+            magnitude,
+        } => {
+            let sign = if negative { "-" } else { "" };
+            writeln!(
+                text,
+                "{} is {}{}, {}",
+                literal.text,
+                sign,
+                magnitude,
+                match literal.suffix {
+                    Some(ty) => ty.with_article(),
+                    None => "an i32 unless the context picks another type".to_string(),
+                }
+            )
+            .unwrap();
+            writeln!(
+                text,
+                "    binary {}0b{:b}  octal {}0o{:o}  hex {}0x{:x}",
+                sign, magnitude, sign, magnitude, sign, magnitude
+            )
+            .unwrap();
+        }
+        Number::Float(value) => {
+            writeln!(
+                text,
+                "{} is {:?}, {}",
+                literal.text,
+                value,
+                match literal.suffix {
+                    Some(ty) => ty.with_article(),
+                    None => "an f64 unless the context picks f32".to_string(),
+                }
+            )
+            .unwrap();
+        }
+    }
+    let types: Vec<&str> = literal.types().iter().map(|ty| ty.name()).collect();
+    writeln!(text, "    fits in {}", types.join(" ")).unwrap();
+    text
+}
+
+// The type of a literal as rustc names it in errors, {integer} or {float} without a suffix
+fn type_label(literal: &Literal) -> &'static str {
+    match (literal.suffix, literal.value) {
+        (Some(ty), _) => ty.name(),
+        (None, Number::Integer { .. }) => "{integer}",
+        (None, Number::Float(_)) => "{float}",
+    }
+}
+
+fn describe_operation(a: &Literal, op: Op, b: &Literal) -> Result<String> {
+    // Rust never converts between integers and floats on its own, 1.0 / 0 doesn't compile
+    let is_float = |literal: &Literal| matches!(literal.value, Number::Float(_));
+    let mismatched = match (a.suffix, b.suffix) {
+        (Some(left), Some(right)) => left != right,
+        _ => is_float(a) != is_float(b),
+    };
+    if mismatched {
+        return Err(Error::Validation(format!(
+            "mismatched types {} and {}, both sides need the same type",
+            type_label(a),
+            type_label(b)
+        )));
+    }
+
+    let ty = match (a.suffix, b.suffix) {
+        (Some(ty), _) | (_, Some(ty)) => ty,
+        (None, None) => match (a.value, b.value) {
+            (Number::Integer { .. }, Number::Integer { .. }) => NumericType::I32,
+            _ => NumericType::F64,
+        },
+    };
+
+    let mut text = format!("{} {} {} as {}\n", a.text, op.symbol(), b.text, ty.name());
+    match arithmetic(ty, a, op, b)? {
+        Outcome::Integer {
+            checked,
+            wrapping,
+            saturating,
+            overflowing,
+        } => {
+            let checked = match checked {
+                Some(value) => format!("Some({})", value),
+                None => "None".to_string(),
+            };
+            for (mode, result) in [
+                ("checked", checked),
+                ("wrapping", wrapping),
+                ("saturating", saturating),
+                ("overflowing", overflowing),
+            ] {
+                writeln!(text, "    {:<13}{}", mode, result).unwrap();
+            }
+        }
+        Outcome::Float(result) => writeln!(text, "    {}", result).unwrap(),
+    }
+    Ok(text)
+}
+
+// One line of the explorer: a type name, a literal or `a op b`
+pub fn evaluate(line: &str) -> Result<String> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    match words.as_slice() {
+        [name] if NumericType::from_name(name).is_some() => {
+            let ty = NumericType::from_name(name).expect("checked by the guard");
+            Ok(format!("{}\n", ty.describe()))
+        }
+        [literal] => Ok(describe_literal(&parse_literal(literal)?)),
+        [a, op, b] => {
+            let op = Op::parse(op).ok_or_else(|| {
+                Error::Validation(format!("unknown operator `{}`, use + - * or /", op))
+            })?;
+            describe_operation(&parse_literal(a)?, op, &parse_literal(b)?)
+        }
+        _ => Err(Error::Validation(
+            "expected a type (u8), a literal (0xff_u8) or an operation (250u8 + 10)".to_string(),
+        )),
+    }
+}
+
+// The size and range of every type, optionally without isize and usize since those depend on the
+// target
+pub fn range_table(include_pointer_sized: bool) -> String {
+    let mut table = String::new();
+    for ty in NumericType::ALL {
+        if include_pointer_sized || !ty.is_pointer_sized() {
+            writeln!(table, "{}", ty.describe()).unwrap();
+        }
+    }
+    table
+}
+
+// Asks for lines until an empty line or the end of the input
+pub fn explore(input: &mut dyn BufRead, out: &mut dyn Write) -> io::Result<()> {
+    writeln!(
+        out,
+        "type a type (i8), a literal (0xff, 1_000u16, 2.5e3) or an operation (250u8 + 10), an empty line quits"
+    )?;
+    loop {
+        write!(out, "> ")?;
+        out.flush()?;
+
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 || line.trim().is_empty() {
+            return Ok(());
+        }
+        match evaluate(&line) {
+            Ok(answer) => write!(out, "{}", answer)?,
+            Err(error) => writeln!(out, "error: {}", error)?,
+        }
+    }
+}
@@ -0,0 +1,310 @@
+// Learner progress
+//
+// Every finished lesson, quiz and exercise check is appended as one line to a plain text file:
+//
+//     <unix seconds>\t<learner>\t<lesson|quiz|exercise>\t<id>\t<score in percent or ->
+//
+// The file lives at $PRACTICE_ONE_PROGRESS, or ~/.practice_one_progress by default, and the learner
+// is $PRACTICE_ONE_LEARNER or the login name.
+
+use crate::{
+    exercises, quiz,
+    registry::{Lesson, Registry},
+};
+use std::{
+    env,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+const HEADER: &str = "# practice_one progress: timestamp, learner, kind, id, score";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Lesson,
+    Quiz,
+    Exercise,
+}
+
+impl Kind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Kind::Lesson => "lesson",
+            Kind::Quiz => "quiz",
+            Kind::Exercise => "exercise",
+        }
+    }
+
+    fn parse(text: &str) -> Option<Kind> {
+        match text {
+            "lesson" => Some(Kind::Lesson),
+            "quiz" => Some(Kind::Quiz),
+            "exercise" => Some(Kind::Exercise),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub timestamp: u64,
+    pub learner: String,
+    pub kind: Kind,
+    // tut8::advanced_pattern_matching for lessons, tut7 for quizzes, the exercise name for exercises
+    pub id: String,
+    pub score: Option<usize>,
+}
+
+impl Record {
+    fn to_line(&self) -> String {
+        let score = self
+            .score
+            .map_or("-".to_string(), |score| score.to_string());
+        format!(
+            "{}\t{}\t{}\t{}\t{}",
+            self.timestamp,
+            self.learner,
+            self.kind.as_str(),
+            self.id,
+            score
+        )
+    }
+
+    fn parse(line: &str) -> Option<Record> {
+        let mut fields = line.split('\t');
+        let record = Record {
+            timestamp: fields.next()?.parse().ok()?,
+            learner: fields.next()?.to_string(),
+            kind: Kind::parse(fields.next()?)?,
+            id: fields.next()?.to_string(),
+            score: fields.next()?.parse().ok(),
+        };
+        fields.next().is_none().then_some(record)
+    }
+}
+
+pub struct Progress {
+    // None when the file couldn't be read, the records are then only kept in memory
+    path: Option<PathBuf>,
+    learner: String,
+    records: Vec<Record>,
+}
+
+pub fn default_path() -> PathBuf {
+    if let Some(path) = env::var_os("PRACTICE_ONE_PROGRESS") {
+        return PathBuf::from(path);
+    }
+    match env::var_os("HOME").or_else(|| env::var_os("USERPROFILE")) {
+        Some(home) => Path::new(&home).join(".practice_one_progress"),
+        None => PathBuf::from(".practice_one_progress"),
+    }
+}
+
+pub fn default_learner() -> String {
+    ["PRACTICE_ONE_LEARNER", "USER", "USERNAME"]
+        .iter()
+        .find_map(|name| {
+            env::var(name)
+                .ok()
+                .filter(|learner| !learner.trim().is_empty())
+        })
+        .map(|learner| learner.replace(['\t', '\n'], " "))
+        .unwrap_or_else(|| "learner".to_string())
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+// "2026-10-18 09:30 UTC", the date is computed from the days since 1970-01-01
+pub fn format_timestamp(timestamp: u64) -> String {
+    let days = (timestamp / 86_400) as i64;
+    let minutes_of_day = timestamp % 86_400 / 60;
+
+    // Civil date from days, Howard Hinnant's algorithm
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        minutes_of_day / 60,
+        minutes_of_day % 60
+    )
+}
+
+impl Progress {
+    // Loads the records of every learner, a missing file is an empty history
+    pub fn open(path: PathBuf, learner: String) -> io::Result<Progress> {
+        let records = match fs::read_to_string(&path) {
+            Ok(text) => text
+                .lines()
+                .filter(|line| !line.starts_with('#') && !line.trim().is_empty())
+                .filter_map(Record::parse)
+                .collect(),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(error) => return Err(error),
+        };
+        Ok(Progress {
+            path: Some(path),
+            learner,
+            records,
+        })
+    }
+
+    pub fn open_default() -> io::Result<Progress> {
+        Progress::open(default_path(), default_learner())
+    }
+
+    // Progress that starts empty and is never written, for when the file can't be used
+    pub fn in_memory(learner: String) -> Progress {
+        Progress {
+            path: None,
+            learner,
+            records: Vec::new(),
+        }
+    }
+
+    pub fn learner(&self) -> &str {
+        &self.learner
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    // Records of the current learner, oldest first
+    pub fn records(&self) -> impl Iterator<Item = &Record> {
+        self.records
+            .iter()
+            .filter(move |record| record.learner == self.learner)
+    }
+
+    pub fn record(&mut self, kind: Kind, id: &str, score: Option<usize>) -> io::Result<()> {
+        let record = Record {
+            timestamp: now(),
+            learner: self.learner.clone(),
+            kind,
+            id: id.to_string(),
+            score,
+        };
+
+        if let Some(path) = &self.path {
+            let is_new = !path.exists();
+            let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+            if is_new {
+                writeln!(file, "{}", HEADER)?;
+            }
+            writeln!(file, "{}", record.to_line())?;
+        }
+
+        self.records.push(record);
+        Ok(())
+    }
+
+    pub fn record_lesson(&mut self, lesson: &dyn Lesson) -> io::Result<()> {
+        self.record(Kind::Lesson, &lesson.qualified_id(), None)
+    }
+
+    fn has_done(&self, kind: Kind, id: &str) -> bool {
+        self.records()
+            .any(|record| record.kind == kind && record.id == id)
+    }
+
+    fn best_score(&self, kind: Kind, id: &str) -> Option<usize> {
+        self.records()
+            .filter(|record| record.kind == kind && record.id == id)
+            .filter_map(|record| record.score)
+            .max()
+    }
+
+    pub fn last_lesson(&self) -> Option<&Record> {
+        self.records()
+            .filter(|record| record.kind == Kind::Lesson)
+            .max_by_key(|record| record.timestamp)
+    }
+
+    // Index in the registry of the lesson after the last one the learner finished, None when that
+    // was the last lesson
+    pub fn resume_index(&self, registry: &Registry) -> Option<usize> {
+        let last = self.last_lesson()?;
+        let lessons: Vec<&dyn Lesson> = registry.lessons().collect();
+        let position = lessons
+            .iter()
+            .position(|lesson| lesson.qualified_id() == last.id)?;
+        (position + 1 < lessons.len()).then_some(position + 1)
+    }
+
+    // One line per tutorial, e.g. "tut8   lessons 1/1   quiz 80%"
+    pub fn dashboard(&self, registry: &Registry) -> String {
+        let mut dashboard = format!("Progress of {}\n", self.learner);
+
+        for tutorial in registry.tutorials() {
+            let lessons: Vec<&dyn Lesson> = registry.lessons_in(tutorial).collect();
+            let done = lessons
+                .iter()
+                .filter(|lesson| self.has_done(Kind::Lesson, &lesson.qualified_id()))
+                .count();
+            let mut line = format!(
+                "{:<7}lessons {}/{}",
+                format!("tut{}", tutorial),
+                done,
+                lessons.len()
+            );
+
+            if quiz::tutorials_with_quiz().contains(&tutorial) {
+                match self.best_score(Kind::Quiz, &format!("tut{}", tutorial)) {
+                    Some(score) => line.push_str(&format!("   quiz {}%", score)),
+                    None => line.push_str("   quiz -"),
+                }
+            }
+
+            let tutorial_exercises: Vec<&str> = exercises::EXERCISES
+                .iter()
+                .filter(|exercise| exercise.tutorial == tutorial)
+                .map(|exercise| exercise.name)
+                .collect();
+            if !tutorial_exercises.is_empty() {
+                let passed = tutorial_exercises
+                    .iter()
+                    .filter(|name| self.best_score(Kind::Exercise, name) == Some(100))
+                    .count();
+                line.push_str(&format!(
+                    "   exercises {}/{}",
+                    passed,
+                    tutorial_exercises.len()
+                ));
+            }
+
+            dashboard.push_str(&line);
+            dashboard.push('\n');
+        }
+
+        if let Some(last) = self.last_lesson() {
+            dashboard.push_str(&format!(
+                "\nlast lesson: {} on {}\n",
+                last.id,
+                format_timestamp(last.timestamp)
+            ));
+        }
+        dashboard
+    }
+}
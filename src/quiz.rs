@@ -0,0 +1,342 @@
+// Quizzes for the tutorials
+//
+// The question banks are plain text files in quizzes/, one per tutorial, embedded into the
+// binary. See quizzes/tut3.txt for the format.
+
+use crate::{
+    error::{Error, Result},
+    registry::Registry,
+};
+use std::io::{self, BufRead, Write};
+
+const BANKS: &[(u8, &str)] = &[
+    (2, include_str!("../quizzes/tut2.txt")),
+    (3, include_str!("../quizzes/tut3.txt")),
+    (4, include_str!("../quizzes/tut4.txt")),
+    (5, include_str!("../quizzes/tut5.txt")),
+    (6, include_str!("../quizzes/tut6.txt")),
+    (7, include_str!("../quizzes/tut7.txt")),
+    (8, include_str!("../quizzes/tut8.txt")),
+    (9, include_str!("../quizzes/tut9.txt")),
+    (10, include_str!("../quizzes/tut10.txt")),
+    (11, include_str!("../quizzes/tut11.txt")),
+];
+
+#[derive(Debug)]
+pub enum Answer {
+    // 1-based number of the right option
+    Choice {
+        options: Vec<String>,
+        correct: usize,
+    },
+    Prints(String),
+    Compiles(bool),
+}
+
+#[derive(Debug)]
+pub struct Question {
+    pub lesson: String,
+    pub prompt: String,
+    pub code: Option<String>,
+    pub answer: Answer,
+    pub explanation: String,
+    // Line of `== question` in the bank, for error messages
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Score {
+    pub correct: usize,
+    pub total: usize,
+}
+
+impl Score {
+    pub fn percent(&self) -> usize {
+        (self.correct * 100).checked_div(self.total).unwrap_or(0)
+    }
+}
+
+pub fn tutorials_with_quiz() -> Vec<u8> {
+    BANKS.iter().map(|(tutorial, _)| *tutorial).collect()
+}
+
+pub fn bank(tutorial: u8) -> Option<&'static str> {
+    BANKS
+        .iter()
+        .find(|(number, _)| *number == tutorial)
+        .map(|(_, bank)| *bank)
+}
+
+// Fields of one question while it is being read
+#[derive(Default)]
+struct Draft {
+    line: usize,
+    kind: Option<String>,
+    lesson: Option<String>,
+    prompt: Option<String>,
+    code: Option<String>,
+    options: Vec<String>,
+    answer: Option<String>,
+    explanation: Option<String>,
+}
+
+impl Draft {
+    fn finish(self) -> Result<Question> {
+        let line = self.line;
+        let invalid = |message: String| Error::Parse {
+            line,
+            column: None,
+            message,
+        };
+        let missing = |field: &str| invalid(format!("question has no `{}:`", field));
+
+        let kind = self.kind.ok_or_else(|| missing("kind"))?;
+        let answer_text = self.answer.ok_or_else(|| missing("answer"))?;
+        let answer = match kind.as_str() {
+            "choice" => {
+                let correct = answer_text
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|number| (1..=self.options.len()).contains(number))
+                    .ok_or_else(|| {
+                        invalid(format!(
+                            "question has answer `{}`, but {} options",
+                            answer_text,
+                            self.options.len()
+                        ))
+                    })?;
+                Answer::Choice {
+                    options: self.options,
+                    correct,
+                }
+            }
+            "prints" => Answer::Prints(answer_text),
+            "compiles" => match answer_text.as_str() {
+                "yes" => Answer::Compiles(true),
+                "no" => Answer::Compiles(false),
+                other => {
+                    return Err(invalid(format!(
+                        "question has answer `{}`, expected yes or no",
+                        other
+                    )))
+                }
+            },
+            other => return Err(invalid(format!("question has unknown kind `{}`", other))),
+        };
+
+        Ok(Question {
+            lesson: self.lesson.ok_or_else(|| missing("lesson"))?,
+            prompt: self.prompt.ok_or_else(|| missing("prompt"))?,
+            code: self.code,
+            answer,
+            explanation: self.explanation.unwrap_or_default(),
+            line,
+        })
+    }
+}
+
+pub fn parse(bank: &str) -> Result<Vec<Question>> {
+    let mut questions = Vec::new();
+    let mut draft: Option<Draft> = None;
+    let mut lines = bank.lines().enumerate();
+
+    while let Some((index, line)) = lines.next() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        if trimmed == "== question" {
+            if let Some(finished) = draft.take() {
+                questions.push(finished.finish()?);
+            }
+            draft = Some(Draft {
+                line: index + 1,
+                ..Draft::default()
+            });
+            continue;
+        }
+
+        let invalid = |message: String| Error::Parse {
+            line: index + 1,
+            column: None,
+            message,
+        };
+        let current = draft
+            .as_mut()
+            .ok_or_else(|| invalid("expected `== question` first".to_string()))?;
+
+        if trimmed == "code:" {
+            let mut code = Vec::new();
+            loop {
+                match lines.next() {
+                    Some((_, line)) if line.trim() == "end" => break,
+                    Some((_, line)) => code.push(line.strip_prefix("    ").unwrap_or(line)),
+                    None => return Err(invalid("`code:` without `end`".to_string())),
+                }
+            }
+            current.code = Some(code.join("\n"));
+            continue;
+        }
+
+        let (key, value) = trimmed
+            .split_once(':')
+            .ok_or_else(|| invalid(format!("expected `key: value`, found `{}`", trimmed)))?;
+        let value = value.trim().to_string();
+        match key {
+            "kind" => current.kind = Some(value),
+            "lesson" => current.lesson = Some(value),
+            "prompt" => current.prompt = Some(value),
+            "option" => current.options.push(value),
+            "answer" => current.answer = Some(value),
+            "explain" => current.explanation = Some(value),
+            other => return Err(invalid(format!("unknown key `{}`", other))),
+        }
+    }
+
+    if let Some(finished) = draft {
+        questions.push(finished.finish()?);
+    }
+    Ok(questions)
+}
+
+// Parses the bank of a tutorial and checks that every question points at an existing lesson
+pub fn load(registry: &Registry, tutorial: u8) -> Result<Vec<Question>> {
+    let bank = bank(tutorial).ok_or_else(|| Error::NotFound {
+        what: "quiz",
+        name: format!("tut{}", tutorial),
+        available: tutorials_with_quiz()
+            .iter()
+            .map(|tutorial| format!("tut{}", tutorial))
+            .collect(),
+    })?;
+    let file = format!("could not load quizzes/tut{}.txt", tutorial);
+    let questions = parse(bank).map_err(|error| error.context(file.as_str()))?;
+
+    for question in &questions {
+        if registry.find(tutorial, &question.lesson).is_none() {
+            let error = Error::Parse {
+                line: question.line,
+                column: None,
+                message: format!("question points at unknown lesson `{}`", question.lesson),
+            };
+            return Err(error.context(file));
+        }
+    }
+    Ok(questions)
+}
+
+// Collapses whitespace so that "value is  1" and "value is 1" count as the same output
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn ask(question: &Question, number: usize, out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "Question {}: {}", number, question.prompt)?;
+    if let Some(code) = &question.code {
+        writeln!(out)?;
+        for line in code.lines() {
+            writeln!(out, "    {}", line)?;
+        }
+        writeln!(out)?;
+    }
+
+    match &question.answer {
+        Answer::Choice { options, .. } => {
+            for (index, option) in options.iter().enumerate() {
+                writeln!(out, "  {}. {}", index + 1, option)?;
+            }
+            write!(out, "your answer (number) > ")?;
+        }
+        Answer::Prints(_) => write!(out, "your answer (the printed text) > ")?,
+        Answer::Compiles(_) => write!(out, "your answer (yes/no) > ")?,
+    }
+    out.flush()
+}
+
+fn is_correct(answer: &Answer, given: &str) -> bool {
+    match answer {
+        Answer::Choice { correct, .. } => given.parse::<usize>() == Ok(*correct),
+        Answer::Prints(expected) => normalize(given) == normalize(expected),
+        Answer::Compiles(compiles) => match given.to_ascii_lowercase().as_str() {
+            "y" | "yes" => *compiles,
+            "n" | "no" => !*compiles,
+            _ => false,
+        },
+    }
+}
+
+fn correct_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Choice { options, correct } => format!("{}. {}", correct, options[correct - 1]),
+        Answer::Prints(expected) => expected.clone(),
+        Answer::Compiles(true) => "yes, it compiles".to_string(),
+        Answer::Compiles(false) => "no, it doesn't compile".to_string(),
+    }
+}
+
+// Asks every question of the tutorial and returns the score. Running out of input counts the
+// remaining questions as wrong.
+pub fn run(
+    registry: &Registry,
+    tutorial: u8,
+    questions: &[Question],
+    input: &mut dyn BufRead,
+    out: &mut dyn Write,
+) -> io::Result<Score> {
+    let mut score = Score {
+        correct: 0,
+        total: questions.len(),
+    };
+    writeln!(
+        out,
+        "Quiz for tut{}: {} questions",
+        tutorial,
+        questions.len()
+    )?;
+
+    for (index, question) in questions.iter().enumerate() {
+        ask(question, index + 1, out)?;
+
+        let mut given = String::new();
+        if input.read_line(&mut given)? == 0 {
+            writeln!(out)?;
+            break;
+        }
+
+        if is_correct(&question.answer, given.trim()) {
+            score.correct += 1;
+            writeln!(out, "Correct!")?;
+        } else {
+            writeln!(
+                out,
+                "Not quite, the answer is: {}",
+                correct_answer(&question.answer)
+            )?;
+        }
+        if !question.explanation.is_empty() {
+            writeln!(out, "{}", question.explanation)?;
+        }
+        if let Some(lesson) = registry.find(tutorial, &question.lesson) {
+            writeln!(
+                out,
+                "See tut{}::{} (practice_one tut{} {})",
+                tutorial,
+                lesson.entry_name(),
+                tutorial,
+                lesson.id()
+            )?;
+        }
+    }
+
+    writeln!(out)?;
+    writeln!(
+        out,
+        "Score: {}/{} ({}%)",
+        score.correct,
+        score.total,
+        score.percent()
+    )?;
+    Ok(score)
+}
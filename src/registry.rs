@@ -0,0 +1,116 @@
+// Lesson registry
+//
+// Each tutN module describes its lessons in a `register` function at the bottom of the file, so
+// anything that needs to walk over the lessons (the runner, docs, tests) asks the registry instead
+// of keeping its own hard-coded list.
+
+use std::io::{self, Write};
+
+pub trait Lesson {
+    // Short name, unique within a tutorial, e.g. "strings"
+    fn id(&self) -> &'static str;
+    fn title(&self) -> &'static str;
+    fn tutorial(&self) -> u8;
+    fn tags(&self) -> &'static [&'static str];
+    // Name of the function in the tutorial module that the lesson runs
+    fn entry_name(&self) -> &'static str;
+    // File names inside images/ that go with the lesson
+    fn diagrams(&self) -> &'static [&'static str];
+    // Lessons write to `out` instead of printing, so the output can be captured and checked
+    fn run(&self, out: &mut dyn Write) -> io::Result<()>;
+
+    fn qualified_id(&self) -> String {
+        format!("tut{}::{}", self.tutorial(), self.id())
+    }
+}
+
+// The lesson description used by every tutorial module
+pub struct LessonInfo {
+    pub id: &'static str,
+    pub title: &'static str,
+    pub tutorial: u8,
+    pub tags: &'static [&'static str],
+    pub entry_name: &'static str,
+    pub entry: fn(&mut dyn Write) -> io::Result<()>,
+    pub diagrams: &'static [&'static str],
+}
+
+impl Lesson for LessonInfo {
+    fn id(&self) -> &'static str {
+        self.id
+    }
+
+    fn title(&self) -> &'static str {
+        self.title
+    }
+
+    fn tutorial(&self) -> u8 {
+        self.tutorial
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        self.tags
+    }
+
+    fn entry_name(&self) -> &'static str {
+        self.entry_name
+    }
+
+    fn diagrams(&self) -> &'static [&'static str] {
+        self.diagrams
+    }
+
+    fn run(&self, out: &mut dyn Write) -> io::Result<()> {
+        (self.entry)(out)
+    }
+}
+
+#[derive(Default)]
+pub struct Registry {
+    lessons: Vec<Box<dyn Lesson>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Registry filled with the lessons of every tutorial, in tutorial order
+    pub fn with_all_lessons() -> Self {
+        let mut registry = Registry::new();
+        crate::lessons::tut2::register(&mut registry);
+        crate::lessons::tut3::register(&mut registry);
+        crate::lessons::tut4::register(&mut registry);
+        crate::lessons::tut5::register(&mut registry);
+        crate::lessons::tut6::register(&mut registry);
+        crate::lessons::tut7::register(&mut registry);
+        crate::lessons::tut8::register(&mut registry);
+        crate::lessons::tut9::register(&mut registry);
+        crate::lessons::tut10::register(&mut registry);
+        crate::lessons::tut11::register(&mut registry);
+        registry
+    }
+
+    pub fn register(&mut self, lesson: impl Lesson + 'static) {
+        self.lessons.push(Box::new(lesson));
+    }
+
+    pub fn lessons(&self) -> impl Iterator<Item = &dyn Lesson> {
+        self.lessons.iter().map(|lesson| lesson.as_ref())
+    }
+
+    pub fn tutorials(&self) -> Vec<u8> {
+        let mut tutorials: Vec<u8> = self.lessons().map(|lesson| lesson.tutorial()).collect();
+        tutorials.dedup();
+        tutorials
+    }
+
+    pub fn lessons_in(&self, tutorial: u8) -> impl Iterator<Item = &dyn Lesson> {
+        self.lessons()
+            .filter(move |lesson| lesson.tutorial() == tutorial)
+    }
+
+    pub fn find(&self, tutorial: u8, id: &str) -> Option<&dyn Lesson> {
+        self.lessons_in(tutorial).find(|lesson| lesson.id() == id)
+    }
+}
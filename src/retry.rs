@@ -0,0 +1,204 @@
+// Retrying and falling back
+//
+// Helpers for operations that fail now and then, like reading a file another process is writing or
+// talking to a socket:
+//
+//     let contents = Retry::new(3)
+//         .backoff(Backoff::exponential(Duration::from_millis(10), Duration::from_secs(1)))
+//         .run(|| fs::read_to_string(&path))?;
+//
+//     let port = Fallback::new(|| env_port())
+//         .then(|| config_port())
+//         .run_or(8080);
+//
+// Waiting between attempts goes through a Clock, so tests can pass one that only records the
+// delays instead of sleeping. When everything fails the error of every attempt is kept.
+
+use std::{error, fmt, thread, time::Duration};
+
+pub trait Clock {
+    fn sleep(&self, duration: Duration);
+}
+
+// The real clock, waits with thread::sleep
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+// Lets a caller keep its clock and look at it after the retries, e.g. a clock that records delays
+impl<C: Clock + ?Sized> Clock for &C {
+    fn sleep(&self, duration: Duration) {
+        (**self).sleep(duration);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backoff {
+    // Retry right away
+    None,
+    // The same delay before every retry
+    Fixed(Duration),
+    // The delay doubles before every retry, up to `max`
+    Exponential { initial: Duration, max: Duration },
+}
+
+impl Backoff {
+    pub fn exponential(initial: Duration, max: Duration) -> Backoff {
+        Backoff::Exponential { initial, max }
+    }
+
+    // Delay before the given retry, 1 is the first retry (the second attempt)
+    pub fn delay(&self, retry: u32) -> Duration {
+        match *self {
+            Backoff::None => Duration::ZERO,
+            Backoff::Fixed(delay) => delay,
+            Backoff::Exponential { initial, max } => {
+                let doublings = retry.saturating_sub(1);
+                2_u32
+                    .checked_pow(doublings)
+                    .and_then(|factor| initial.checked_mul(factor))
+                    .map_or(max, |delay| delay.min(max))
+            }
+        }
+    }
+}
+
+// The errors of every failed attempt, oldest first
+#[derive(Debug)]
+pub struct AllFailed<E> {
+    pub errors: Vec<E>,
+}
+
+impl<E> AllFailed<E> {
+    pub fn last(&self) -> Option<&E> {
+        self.errors.last()
+    }
+
+    pub fn into_last(self) -> Option<E> {
+        self.errors.into_iter().last()
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for AllFailed<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "all {} attempts failed", self.errors.len())?;
+        for (number, error) in self.errors.iter().enumerate() {
+            write!(
+                f,
+                "{} {}: {}",
+                if number == 0 { ":" } else { ";" },
+                number + 1,
+                error
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: error::Error> error::Error for AllFailed<E> {}
+
+pub struct Retry<C = SystemClock> {
+    attempts: u32,
+    backoff: Backoff,
+    clock: C,
+}
+
+impl Retry<SystemClock> {
+    // Tries up to `attempts` times (at least once) without waiting in between
+    pub fn new(attempts: u32) -> Retry<SystemClock> {
+        Retry {
+            attempts: attempts.max(1),
+            backoff: Backoff::None,
+            clock: SystemClock,
+        }
+    }
+}
+
+impl<C: Clock> Retry<C> {
+    pub fn backoff(self, backoff: Backoff) -> Retry<C> {
+        Retry { backoff, ..self }
+    }
+
+    pub fn clock<D: Clock>(self, clock: D) -> Retry<D> {
+        Retry {
+            attempts: self.attempts,
+            backoff: self.backoff,
+            clock,
+        }
+    }
+
+    // Calls `operation` until it returns Ok or the attempts run out
+    pub fn run<T, E>(&self, operation: impl FnMut() -> Result<T, E>) -> Result<T, AllFailed<E>> {
+        self.run_if(operation, |_| true)
+    }
+
+    // Like `run`, but stops right away on an error `should_retry` says is not worth retrying,
+    // e.g. a missing file when only interrupted reads are expected to go away
+    pub fn run_if<T, E>(
+        &self,
+        mut operation: impl FnMut() -> Result<T, E>,
+        should_retry: impl Fn(&E) -> bool,
+    ) -> Result<T, AllFailed<E>> {
+        let mut errors = Vec::new();
+
+        for attempt in 1..=self.attempts {
+            if attempt > 1 {
+                self.clock.sleep(self.backoff.delay(attempt - 1));
+            }
+            match operation() {
+                Ok(value) => return Ok(value),
+                Err(error) => {
+                    let retry = should_retry(&error);
+                    errors.push(error);
+                    if !retry {
+                        break;
+                    }
+                }
+            }
+        }
+        Err(AllFailed { errors })
+    }
+}
+
+// Tries a list of alternatives in order and returns the first Ok, "try A, then B, then a default"
+pub struct Fallback<'a, T, E> {
+    steps: Vec<Box<dyn FnOnce() -> Result<T, E> + 'a>>,
+}
+
+impl<'a, T, E> Fallback<'a, T, E> {
+    pub fn new(first: impl FnOnce() -> Result<T, E> + 'a) -> Self {
+        Fallback {
+            steps: vec![Box::new(first)],
+        }
+    }
+
+    pub fn then(mut self, next: impl FnOnce() -> Result<T, E> + 'a) -> Self {
+        self.steps.push(Box::new(next));
+        self
+    }
+
+    // The first Ok, or the errors of every step when none of them worked
+    pub fn run(self) -> Result<T, AllFailed<E>> {
+        let mut errors = Vec::new();
+        for step in self.steps {
+            match step() {
+                Ok(value) => return Ok(value),
+                Err(error) => errors.push(error),
+            }
+        }
+        Err(AllFailed { errors })
+    }
+
+    pub fn run_or(self, default: T) -> T {
+        self.run().unwrap_or(default)
+    }
+
+    pub fn run_or_else(self, default: impl FnOnce(AllFailed<E>) -> T) -> T {
+        self.run().unwrap_or_else(default)
+    }
+}
@@ -0,0 +1,450 @@
+// Lesson runner used by the practice_one binary
+//
+// The lessons themselves come from the registry, the runner only turns the command line into a
+// choice of lessons so switching lessons doesn't need editing main() and recompiling.
+
+use crate::{
+    borrows, docs,
+    error::{self, Error, Result},
+    exercises::{self, CheckReport},
+    html, interactive, layout, numeric,
+    progress::{self, Kind, Progress},
+    quiz,
+    registry::{Lesson, Registry},
+    search::{self, Index},
+};
+use std::{
+    fs,
+    io::{self, IsTerminal, Write},
+    path::Path,
+};
+
+const USAGE: &str = "usage:
+    practice_one interactive          browse the lessons in a menu (the default in a terminal)
+    practice_one list                 list every lesson
+    practice_one list --ids           list the qualified id of every lesson, one per line
+    practice_one <tutorial>           run every lesson of a tutorial, e.g. `practice_one tut10`
+    practice_one <tutorial> <lesson>  run one lesson, e.g. `practice_one tut10 strings`
+    practice_one <lesson>             run a lesson by name when the name is unique
+    practice_one info <tutorial> <lesson>
+                                      show the tags, function and diagrams of a lesson
+    practice_one docs [out_dir]       write the tutorials as Markdown, into docs/ by default
+    practice_one html [out_dir]       write the tutorials as a static HTML site, into site/ by default
+    practice_one quiz <tutorial>      answer the quiz of a tutorial, e.g. `practice_one quiz tut7`
+    practice_one exercises            list the exercises
+    practice_one check <exercise>     run the hidden tests of an exercise, e.g. `practice_one check tut9_role`
+    practice_one search <words>...    search the comments, code and diagrams, e.g. `practice_one search deref coercion`
+    practice_one progress             show the lessons, quizzes and exercises you have finished
+    practice_one explore-numbers [line]
+                                      explore the numeric types: a type (u8), a literal (0xff_u8) or an
+                                      operation (250u8 + 10), asks for lines when none is given
+    practice_one borrows [file]       draw where the borrows of a small snippet are live and which ones
+                                      conflict, `-` reads the snippet from stdin, tut3's example without a file
+    practice_one layout [name]        show the size, alignment and field offsets of the lesson types,
+                                      of one tutorial or type with e.g. `practice_one layout tut5`
+
+progress is kept in $PRACTICE_ONE_PROGRESS (default ~/.practice_one_progress) under the name in
+$PRACTICE_ONE_LEARNER (default $USER)";
+
+// Accepts "tut10", "10" or "TUT10"
+pub fn parse_tutorial(registry: &Registry, arg: &str) -> Option<u8> {
+    let lower = arg.to_ascii_lowercase();
+    let number = lower.strip_prefix("tut").unwrap_or(&lower);
+    let tutorial: u8 = number.parse().ok()?;
+    registry.tutorials().contains(&tutorial).then_some(tutorial)
+}
+
+pub fn print_lessons(registry: &Registry) {
+    for tutorial in registry.tutorials() {
+        println!("tut{}", tutorial);
+        for lesson in registry.lessons_in(tutorial) {
+            println!("    {:<28}{}", lesson.id(), lesson.title());
+        }
+    }
+}
+
+pub fn print_lesson_info(lesson: &dyn Lesson) {
+    println!("{} - {}", lesson.qualified_id(), lesson.title());
+    println!(
+        "    function: tut{}::{}",
+        lesson.tutorial(),
+        lesson.entry_name()
+    );
+    println!("    tags:     {}", lesson.tags().join(", "));
+    for diagram in lesson.diagrams() {
+        println!("    diagram:  images/{}", diagram);
+    }
+}
+
+fn open_progress() -> Result<Progress> {
+    Progress::open_default().map_err(|error| {
+        Error::from(error).context(format!(
+            "could not read the progress file {}",
+            progress::default_path().display()
+        ))
+    })
+}
+
+// Progress is a side feature, so a progress file that can't be read doesn't stop the lesson, quiz
+// or exercise. The learner gets a warning and nothing is saved this time.
+fn progress_or_warn() -> Progress {
+    Progress::open_default().unwrap_or_else(|error| {
+        eprintln!(
+            "warning: could not read the progress file {}: {}, progress won't be saved this time",
+            progress::default_path().display(),
+            error
+        );
+        Progress::in_memory(progress::default_learner())
+    })
+}
+
+// Not being able to save progress shouldn't stop the lesson, quiz or exercise itself
+fn save_progress(progress: &mut Progress, kind: Kind, id: &str, score: Option<usize>) {
+    if let Err(error) = progress.record(kind, id, score) {
+        let path = progress.path().unwrap_or(Path::new("the progress file"));
+        eprintln!(
+            "warning: could not save progress to {}: {}",
+            path.display(),
+            error
+        );
+    }
+}
+
+// A lesson that panics fails like one that returns an error, with the panic message as the cause
+fn run_lesson(progress: &mut Progress, lesson: &dyn Lesson) -> Result<()> {
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+
+    writeln!(out, "== {} ==", lesson.qualified_id())
+        .map_err(Error::from)
+        .and_then(|()| error::catch_panic(|| lesson.run(&mut out)))
+        .and_then(|written| written.map_err(Error::from))
+        .map_err(|error| error.context(format!("{} failed", lesson.qualified_id())))?;
+    save_progress(progress, Kind::Lesson, &lesson.qualified_id(), None);
+    Ok(())
+}
+
+// Runs every lesson of a tutorial even when some of them fail, the failures are reported as they
+// happen and counted at the end
+fn run_tutorial(registry: &Registry, progress: &mut Progress, tutorial: u8) -> Result<()> {
+    let mut failed = 0;
+    for lesson in registry.lessons_in(tutorial) {
+        if let Err(error) = run_lesson(progress, lesson) {
+            eprint!("{}", error::report(&error));
+            failed += 1;
+        }
+    }
+
+    match failed {
+        0 => Ok(()),
+        _ => Err(Error::Validation(format!(
+            "{} of {} lessons in tut{} failed",
+            failed,
+            registry.lessons_in(tutorial).count(),
+            tutorial
+        ))),
+    }
+}
+
+fn tutorial_names(tutorials: &[u8]) -> Vec<String> {
+    tutorials
+        .iter()
+        .map(|tutorial| format!("tut{}", tutorial))
+        .collect()
+}
+
+fn find_lesson<'a>(registry: &'a Registry, tutorial: &str, name: &str) -> Result<&'a dyn Lesson> {
+    let tutorial = parse_tutorial(registry, tutorial).ok_or_else(|| Error::NotFound {
+        what: "tutorial",
+        name: tutorial.to_string(),
+        available: tutorial_names(&registry.tutorials()),
+    })?;
+    registry
+        .find(tutorial, name)
+        .ok_or_else(|| Error::NotFound {
+            what: "lesson",
+            name: format!("tut{}::{}", tutorial, name),
+            available: registry
+                .lessons_in(tutorial)
+                .map(|lesson| lesson.id().to_string())
+                .collect(),
+        })
+}
+
+fn run_interactive(registry: &Registry, progress: &mut Progress) -> Result<()> {
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    interactive::run(registry, progress, &mut stdin.lock(), &mut stdout.lock())
+        .map_err(|error| Error::from(error).context("interactive mode stopped"))
+}
+
+fn generate_docs(registry: &Registry, out_dir: &Path) -> Result<()> {
+    let written = docs::generate(registry, out_dir).map_err(|error| {
+        Error::from(error).context(format!("could not write the docs to {}", out_dir.display()))
+    })?;
+    for path in written {
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn generate_site(registry: &Registry, out_dir: &Path) -> Result<()> {
+    let written = html::generate(registry, out_dir).map_err(|error| {
+        Error::from(error).context(format!("could not write the site to {}", out_dir.display()))
+    })?;
+    for path in written {
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn run_quiz(registry: &Registry, progress: &mut Progress, tutorial: &str) -> Result<()> {
+    let tutorial = parse_tutorial(registry, tutorial)
+        .filter(|tutorial| quiz::tutorials_with_quiz().contains(tutorial))
+        .ok_or_else(|| Error::NotFound {
+            what: "quiz",
+            name: tutorial.to_string(),
+            available: tutorial_names(&quiz::tutorials_with_quiz()),
+        })?;
+    let questions = quiz::load(registry, tutorial)?;
+
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    let score = quiz::run(
+        registry,
+        tutorial,
+        &questions,
+        &mut stdin.lock(),
+        &mut stdout.lock(),
+    )
+    .map_err(|error| Error::from(error).context("quiz stopped"))?;
+
+    save_progress(
+        progress,
+        Kind::Quiz,
+        &format!("tut{}", tutorial),
+        Some(score.percent()),
+    );
+    Ok(())
+}
+
+fn run_search(registry: &Registry, query: &[&str]) {
+    let query = query.join(" ");
+    let words = search::query_words(&query);
+    let index = Index::build(registry);
+    let hits = index.search(&query);
+
+    if hits.is_empty() {
+        println!("nothing found for `{}`", query);
+        return;
+    }
+    for hit in hits.iter().take(10) {
+        println!("{}", search::format_hit(hit, &words));
+    }
+    if hits.len() > 10 {
+        println!("... and {} more", hits.len() - 10);
+    }
+}
+
+fn print_borrows(path: &str) -> Result<()> {
+    let (name, source) = if path == "-" {
+        ("<stdin>", io::read_to_string(io::stdin()))
+    } else {
+        (path, fs::read_to_string(path))
+    };
+    let source =
+        source.map_err(|error| Error::from(error).context(format!("could not read {}", name)))?;
+    let analysis = borrows::analyse(&source).map_err(|error| error.context(name.to_string()))?;
+    print!("{}", analysis);
+    Ok(())
+}
+
+// Takes a tutorial ("tut5") or a type name ("Point" or "tut7::Point")
+fn print_layouts(filter: Option<&str>) -> Result<()> {
+    let all = layout::all();
+    let layouts: Vec<_> = match filter {
+        None => all.clone(),
+        Some(filter) => all
+            .iter()
+            .filter(|layout| {
+                let (tutorial, name) = layout.name.split_once("::").unwrap_or(("", layout.name));
+                [tutorial, name, layout.name].contains(&filter)
+            })
+            .cloned()
+            .collect(),
+    };
+    if layouts.is_empty() {
+        return Err(Error::NotFound {
+            what: "type",
+            name: filter.unwrap_or_default().to_string(),
+            available: all.iter().map(|layout| layout.name.to_string()).collect(),
+        });
+    }
+
+    print!("{}", layout::summary(&layouts));
+    for layout in &layouts {
+        print!("\n{}", layout);
+    }
+    Ok(())
+}
+
+fn print_exercises() {
+    for exercise in exercises::EXERCISES {
+        println!(
+            "{:<24}tut{:<4}{}",
+            exercise.name, exercise.tutorial, exercise.description
+        );
+    }
+}
+
+fn check_exercise(progress: &mut Progress, name: &str) -> Result<()> {
+    let exercise = exercises::find(name).ok_or_else(|| Error::NotFound {
+        what: "exercise",
+        name: name.to_string(),
+        available: exercises::EXERCISES
+            .iter()
+            .map(|exercise| exercise.name.to_string())
+            .collect(),
+    })?;
+    println!(
+        "checking {} ({})",
+        exercise.name,
+        exercises::exercise_dir(exercise)
+            .join("exercise.rs")
+            .display()
+    );
+
+    match exercises::check(exercise)? {
+        CheckReport::CompileError(output) => {
+            println!("{}", output);
+            Err(Error::Validation(format!(
+                "{} does not compile yet, fix the errors above",
+                exercise.name
+            )))
+        }
+        CheckReport::Ran(results) => {
+            for result in &results {
+                println!(
+                    "  {} {}",
+                    if result.passed { "PASS" } else { "FAIL" },
+                    result.name
+                );
+                if let Some(hint) = result.hint.as_ref().filter(|_| !result.passed) {
+                    println!("       hint: {}", hint);
+                }
+            }
+            let passed = results.iter().filter(|result| result.passed).count();
+            println!("{}/{} tests pass", passed, results.len());
+            if !results.is_empty() {
+                let score = passed * 100 / results.len();
+                save_progress(progress, Kind::Exercise, exercise.name, Some(score));
+            }
+
+            if results.is_empty() || passed < results.len() {
+                Err(Error::Validation(format!(
+                    "{} is not done yet",
+                    exercise.name
+                )))
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
+
+pub fn run(args: &[String]) -> Result<()> {
+    let registry = Registry::with_all_lessons();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    match args.as_slice() {
+        [] if io::stdin().is_terminal() => run_interactive(&registry, &mut progress_or_warn()),
+        ["interactive"] => run_interactive(&registry, &mut progress_or_warn()),
+        [] | ["help"] | ["-h"] | ["--help"] => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        ["list"] => {
+            print_lessons(&registry);
+            Ok(())
+        }
+        ["list", "--ids"] => {
+            registry
+                .lessons()
+                .for_each(|lesson| println!("{}", lesson.qualified_id()));
+            Ok(())
+        }
+        ["docs"] => generate_docs(&registry, Path::new("docs")),
+        ["docs", out_dir] => generate_docs(&registry, Path::new(out_dir)),
+        ["html"] => generate_site(&registry, Path::new("site")),
+        ["html", out_dir] => generate_site(&registry, Path::new(out_dir)),
+        ["quiz", tutorial] => run_quiz(&registry, &mut progress_or_warn(), tutorial),
+        ["exercises"] => {
+            print_exercises();
+            Ok(())
+        }
+        ["check", name] => check_exercise(&mut progress_or_warn(), name),
+        ["search"] => Err(Error::Validation(
+            "search needs a query, e.g. `practice_one search borrow`".to_string(),
+        )),
+        ["search", query @ ..] => {
+            run_search(&registry, query);
+            Ok(())
+        }
+        ["progress"] => {
+            print!("{}", open_progress()?.dashboard(&registry));
+            Ok(())
+        }
+        ["explore-numbers"] => {
+            numeric::explore(&mut io::stdin().lock(), &mut io::stdout())?;
+            Ok(())
+        }
+        ["explore-numbers", line @ ..] => {
+            print!("{}", numeric::evaluate(&line.join(" "))?);
+            Ok(())
+        }
+        ["borrows"] => {
+            print!(
+                "{}",
+                borrows::analyse(&crate::lessons::tut3::borrow_rules_snippet(true))?
+            );
+            Ok(())
+        }
+        ["borrows", path] => print_borrows(path),
+        ["layout"] => print_layouts(None),
+        ["layout", name] => print_layouts(Some(name)),
+        ["info", tutorial, name] => {
+            print_lesson_info(find_lesson(&registry, tutorial, name)?);
+            Ok(())
+        }
+        [tutorial, name] => run_lesson(
+            &mut progress_or_warn(),
+            find_lesson(&registry, tutorial, name)?,
+        ),
+        // A whole tutorial, or a lesson whose name is unique
+        [name] => {
+            if let Some(tutorial) = parse_tutorial(&registry, name) {
+                return run_tutorial(&registry, &mut progress_or_warn(), tutorial);
+            }
+            let matches: Vec<&dyn Lesson> = registry
+                .lessons()
+                .filter(|lesson| lesson.id() == *name)
+                .collect();
+            match matches.as_slice() {
+                [lesson] => run_lesson(&mut progress_or_warn(), *lesson),
+                [] => Err(Error::NotFound {
+                    what: "lesson or tutorial",
+                    name: name.to_string(),
+                    available: tutorial_names(&registry.tutorials()),
+                }),
+                _ => Err(Error::Validation(format!(
+                    "lesson name `{}` is ambiguous, pass the tutorial as well",
+                    name
+                ))),
+            }
+        }
+        _ => Err(Error::Validation(format!(
+            "too many arguments\n\n{}",
+            USAGE
+        ))),
+    }
+}
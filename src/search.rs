@@ -0,0 +1,345 @@
+// Full-text search over the tutorials
+//
+// Every top-level item of a tutorial (a function, struct, enum, trait or impl) is one document,
+// together with the comment right above it. Words from comments and strings, identifiers from the
+// code, the tags of the lesson and the names of its diagrams are put in an inverted index. A
+// query is split into words, each word also matches every indexed word it's a prefix of, and the
+// documents are ranked by TF-IDF.
+
+use crate::{
+    docs,
+    highlight::{self, TokenKind},
+    registry::{Lesson, Registry},
+    source::{self, Segment},
+};
+use std::collections::{BTreeMap, HashMap};
+
+// Words that would match nearly every document
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "be", "by", "do", "does", "how", "is", "it", "of", "or", "the",
+    "this", "that", "to", "was", "we", "what", "where", "which", "you",
+];
+
+// How much more a word counts when it names the item, or comes from a tag or a diagram
+const NAME_WEIGHT: f64 = 3.0;
+const TAG_WEIGHT: f64 = 2.0;
+const DIAGRAM_WEIGHT: f64 = 2.0;
+
+// A prefix match ranks below a whole word
+const PREFIX_WEIGHT: f64 = 0.5;
+
+pub struct Document {
+    pub tutorial: u8,
+    // 1-based line of the item, or of the comment for comments that don't describe an item
+    pub line: usize,
+    // None for a comment that isn't about one item, like the introduction of a tutorial
+    pub name: Option<String>,
+    pub text: Vec<String>,
+    pub diagrams: Vec<&'static str>,
+}
+
+impl Document {
+    // tut10::check_deref_coercion, or just tut10
+    pub fn qualified_name(&self) -> String {
+        match &self.name {
+            Some(name) => format!("tut{}::{}", self.tutorial, name),
+            None => format!("tut{}", self.tutorial),
+        }
+    }
+
+    // First line that mentions one of the query words, to show next to the hit
+    fn snippet(&self, words: &[String]) -> Option<String> {
+        let mentions = |text: &str| {
+            let lower = text.to_lowercase();
+            words.iter().any(|word| lower.contains(word.as_str()))
+        };
+        let line = self
+            .text
+            .iter()
+            .map(|line| line.trim())
+            .find(|line| mentions(line))
+            .map(str::to_string)
+            .or_else(|| {
+                self.diagrams
+                    .iter()
+                    .find(|diagram| mentions(diagram))
+                    .map(|diagram| format!("(diagram: images/{})", diagram))
+            })?;
+
+        Some(match line.char_indices().nth(100) {
+            Some((end, _)) => format!("{}...", &line[..end]),
+            None => line,
+        })
+    }
+}
+
+pub struct Hit<'a> {
+    pub document: &'a Document,
+    pub score: f64,
+}
+
+pub struct Index {
+    documents: Vec<Document>,
+    // word -> (document, weighted number of occurrences)
+    postings: BTreeMap<String, Vec<(usize, f64)>>,
+}
+
+// `check_deref_coercion` is indexed as itself and as check, deref and coercion, and
+// `ScriptSagaFollower` as itself and as script, saga and follower
+fn words(text: &str) -> Vec<String> {
+    let mut words = Vec::new();
+
+    for chunk in text
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .filter(|chunk| !chunk.is_empty())
+    {
+        let mut parts: Vec<String> = Vec::new();
+        for piece in chunk.split('_').filter(|piece| !piece.is_empty()) {
+            let mut part = String::new();
+            let mut previous_lower = false;
+            for c in piece.chars() {
+                if c.is_uppercase() && previous_lower {
+                    parts.push(std::mem::take(&mut part));
+                }
+                previous_lower = c.is_lowercase() || c.is_ascii_digit();
+                part.extend(c.to_lowercase());
+            }
+            parts.push(part);
+        }
+
+        if parts.len() > 1 {
+            words.push(chunk.to_lowercase());
+        }
+        words.extend(parts);
+    }
+
+    words.retain(|word| {
+        word.chars().count() > 1
+            && !word.chars().all(|c| c.is_ascii_digit())
+            && !STOP_WORDS.contains(&word.as_str())
+    });
+    words
+}
+
+// Words of one line of code: identifiers, plus the words of comments and strings in it
+fn code_words(line: &str) -> Vec<String> {
+    highlight::tokenize(line)
+        .iter()
+        .filter(|token| {
+            matches!(
+                token.kind,
+                TokenKind::Comment
+                    | TokenKind::String
+                    | TokenKind::Identifier
+                    | TokenKind::Type
+                    | TokenKind::Macro
+            )
+        })
+        .flat_map(|token| words(token.text))
+        .collect()
+}
+
+// Name of the item a top-level line starts, e.g. "check_deref_coercion" for
+// "pub fn check_deref_coercion(arg_one: &str) {}" or "impl Pilot for Human" for an impl block
+fn item_name(line: &str) -> Option<String> {
+    let line = line
+        .strip_prefix("pub(crate) ")
+        .or_else(|| line.strip_prefix("pub "))
+        .unwrap_or(line);
+
+    if line.starts_with("impl") {
+        let header = line.split('{').next().unwrap_or(line).trim();
+        return Some(header.to_string());
+    }
+
+    let (keyword, rest) = line.split_once(' ')?;
+    if ![
+        "fn", "struct", "enum", "trait", "mod", "const", "static", "type",
+    ]
+    .contains(&keyword)
+    {
+        return None;
+    }
+    let name: String = rest
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect();
+    (!name.is_empty()).then_some(name)
+}
+
+// Splits a segment into one document per item, the comment of the segment goes with the first one
+fn segment_documents(tutorial: u8, segment: &Segment) -> Vec<Document> {
+    let new_document = |line: usize, text: Vec<String>| Document {
+        tutorial,
+        line,
+        name: None,
+        text,
+        diagrams: Vec::new(),
+    };
+
+    let mut documents = Vec::new();
+    let mut current = new_document(segment.line, segment.comment.clone());
+    let mut depth = 0;
+    let mut in_block_comment = false;
+
+    for (offset, line) in segment.code.iter().enumerate() {
+        let line_number = segment.line + offset;
+        let trimmed = line.trim();
+
+        if depth == 0 && !in_block_comment {
+            if let Some(name) = item_name(trimmed) {
+                if current.name.is_some() {
+                    documents.push(std::mem::replace(
+                        &mut current,
+                        new_document(line_number, Vec::new()),
+                    ));
+                }
+                current.name = Some(name);
+                current.line = line_number;
+            } else if trimmed.starts_with("#[") && current.name.is_some() {
+                // An attribute belongs to the item below it
+                documents.push(std::mem::replace(
+                    &mut current,
+                    new_document(line_number, Vec::new()),
+                ));
+            }
+        }
+
+        depth += source::brace_delta(line, &mut in_block_comment);
+        current.text.push(line.clone());
+    }
+    documents.push(current);
+
+    documents.retain(|document| document.text.iter().any(|line| !line.trim().is_empty()));
+    documents
+}
+
+impl Index {
+    pub fn build(registry: &Registry) -> Index {
+        let mut index = Index {
+            documents: Vec::new(),
+            postings: BTreeMap::new(),
+        };
+
+        for tutorial in registry.tutorials() {
+            let lessons: Vec<&dyn Lesson> = registry.lessons_in(tutorial).collect();
+            let source = source::tutorial_source(tutorial).unwrap_or_default();
+
+            for segment in source::segments(source)
+                .iter()
+                .filter(|segment| docs::is_lesson_content(segment))
+            {
+                for mut document in segment_documents(tutorial, segment) {
+                    let mut counts: HashMap<String, f64> = HashMap::new();
+                    let mut add = |words: Vec<String>, weight: f64| {
+                        for word in words {
+                            *counts.entry(word).or_default() += weight;
+                        }
+                    };
+
+                    let name = document.name.clone().unwrap_or_default();
+                    add(words(&name), NAME_WEIGHT);
+                    for line in &document.text {
+                        add(code_words(line), 1.0);
+                    }
+                    for lesson in lessons.iter().filter(|lesson| lesson.entry_name() == name) {
+                        for tag in lesson.tags() {
+                            add(words(tag), TAG_WEIGHT);
+                        }
+                        for diagram in lesson.diagrams() {
+                            let name = diagram.rsplit_once('.').map_or(*diagram, |(name, _)| name);
+                            add(words(name), DIAGRAM_WEIGHT);
+                            document.diagrams.push(diagram);
+                        }
+                    }
+
+                    let id = index.documents.len();
+                    for (word, count) in counts {
+                        index.postings.entry(word).or_default().push((id, count));
+                    }
+                    index.documents.push(document);
+                }
+            }
+        }
+        index
+    }
+
+    fn idf(&self, word: &str) -> f64 {
+        let frequency = self.postings.get(word).map_or(0, Vec::len);
+        (1.0 + self.documents.len() as f64 / frequency.max(1) as f64).ln()
+    }
+
+    // Best matching documents first. A query word scores through the best indexed word it matches,
+    // and the scores of the query words add up.
+    pub fn search(&self, query: &str) -> Vec<Hit<'_>> {
+        let query_words = query_words(query);
+        let mut scores: HashMap<usize, f64> = HashMap::new();
+
+        for query_word in &query_words {
+            let mut best: HashMap<usize, f64> = HashMap::new();
+            let matching = self
+                .postings
+                .range(query_word.clone()..)
+                .take_while(|(word, _)| word.starts_with(query_word.as_str()));
+
+            for (word, postings) in matching {
+                let weight = if word == query_word {
+                    1.0
+                } else {
+                    PREFIX_WEIGHT
+                };
+                let idf = self.idf(word);
+                for &(document, count) in postings {
+                    let score = weight * (1.0 + count.ln().max(0.0)) * idf;
+                    let entry = best.entry(document).or_default();
+                    *entry = entry.max(score);
+                }
+            }
+            for (document, score) in best {
+                *scores.entry(document).or_default() += score;
+            }
+        }
+
+        let mut hits: Vec<Hit> = scores
+            .into_iter()
+            .map(|(document, score)| Hit {
+                document: &self.documents[document],
+                score,
+            })
+            .collect();
+        hits.sort_by(|a, b| {
+            b.score.total_cmp(&a.score).then_with(|| {
+                (a.document.tutorial, a.document.line).cmp(&(b.document.tutorial, b.document.line))
+            })
+        });
+        hits
+    }
+}
+
+// The words of a query, lower case and without stop words. A trailing `*` is allowed since every
+// word is matched as a prefix anyway.
+pub fn query_words(query: &str) -> Vec<String> {
+    let mut words: Vec<String> = query
+        .split_whitespace()
+        .flat_map(|word| words(word.trim_end_matches('*')))
+        .collect();
+    // Split the same way as the index, so HashMap::new looks for hashmap, hash, map and new
+    let mut seen = std::collections::HashSet::new();
+    words.retain(|word| seen.insert(word.clone()));
+    words
+}
+
+// file:line, qualified name, score and the line that matched
+pub fn format_hit(hit: &Hit, words: &[String]) -> String {
+    let mut text = format!(
+        "src/lessons/tut{}.rs:{}  {}  ({:.2})",
+        hit.document.tutorial,
+        hit.document.line,
+        hit.document.qualified_name(),
+        hit.score
+    );
+    if let Some(snippet) = hit.document.snippet(words) {
+        text.push_str(&format!("\n    {}", snippet));
+    }
+    text
+}
@@ -0,0 +1,271 @@
+// Access to the source code of the tutorials
+//
+// The explanations live in the comments of the tutN files, so the files are embedded in the binary
+// and the comments around a lesson function are pulled out when a lesson is shown.
+
+const SOURCES: &[(u8, &str)] = &[
+    (2, include_str!("lessons/tut2.rs")),
+    (3, include_str!("lessons/tut3.rs")),
+    (4, include_str!("lessons/tut4.rs")),
+    (5, include_str!("lessons/tut5.rs")),
+    (6, include_str!("lessons/tut6.rs")),
+    (7, include_str!("lessons/tut7.rs")),
+    (8, include_str!("lessons/tut8.rs")),
+    (9, include_str!("lessons/tut9.rs")),
+    (10, include_str!("lessons/tut10.rs")),
+    (11, include_str!("lessons/tut11.rs")),
+];
+
+pub fn tutorial_source(tutorial: u8) -> Option<&'static str> {
+    SOURCES
+        .iter()
+        .find(|(number, _)| *number == tutorial)
+        .map(|(_, source)| *source)
+}
+
+fn is_comment(line: &str) -> bool {
+    let line = line.trim_start();
+    line.starts_with("//") || line.starts_with("/*") || line.starts_with("*/")
+}
+
+// Turns comment lines into plain text, keeping the relative indentation inside /* */ blocks
+fn strip_comment_markers(lines: &[&str]) -> Vec<String> {
+    let mut text = Vec::new();
+    let mut block: Option<Vec<String>> = None;
+
+    for line in lines {
+        let trimmed = line.trim();
+        if let Some(block_lines) = block.as_mut() {
+            match trimmed.strip_suffix("*/") {
+                Some(rest) => {
+                    block_lines.push(rest.trim_end().to_string());
+                    text.extend(dedent(block.take().unwrap_or_default()));
+                }
+                None => block_lines.push(line.trim_end().to_string()),
+            }
+        } else if let Some(rest) = trimmed.strip_prefix("/*") {
+            match rest.strip_suffix("*/") {
+                Some(rest) => text.push(rest.trim().to_string()),
+                None => block = Some(vec![rest.trim().to_string()]),
+            }
+        } else if let Some(rest) = trimmed.strip_prefix("//") {
+            text.push(rest.strip_prefix(' ').unwrap_or(rest).to_string());
+        } else {
+            text.push(String::new());
+        }
+    }
+
+    // Squash runs of empty lines
+    text.dedup_by(|line, previous| line.is_empty() && previous.is_empty());
+    text
+}
+
+fn dedent(lines: Vec<String>) -> Vec<String> {
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    lines
+        .into_iter()
+        .map(|line| line.get(indent..).unwrap_or("").trim_end().to_string())
+        .collect()
+}
+
+// Line index of `fn <name>(` in the source
+pub fn function_line(source: &str, function: &str) -> Option<usize> {
+    let needle = format!("fn {}(", function);
+    source.lines().position(|line| {
+        let line = line.trim_start();
+        line.starts_with(&needle)
+            || line
+                .strip_prefix("pub ")
+                .is_some_and(|rest| rest.starts_with(&needle))
+    })
+}
+
+// The comments written above a function plus the comments at the start of its body
+pub fn explanation(source: &str, function: &str) -> Option<String> {
+    let lines: Vec<&str> = source.lines().collect();
+    let start = function_line(source, function)?;
+
+    // Walk up over comments, blank lines and imports until real code shows up
+    let mut first = start;
+    let mut in_block = false;
+    while first > 0 {
+        let line = lines[first - 1].trim();
+        let closes_block = line.ends_with("*/") && !line.starts_with("//");
+        if in_block || closes_block {
+            in_block = !line.starts_with("/*");
+        } else if !(line.is_empty() || is_comment(line) || line.starts_with("use ")) {
+            break;
+        }
+        first -= 1;
+    }
+    let above: Vec<&str> = lines[first..start]
+        .iter()
+        .copied()
+        .filter(|line| !line.trim_start().starts_with("use "))
+        .collect();
+
+    // Leading comments of the body
+    let body: Vec<&str> = lines[start + 1..]
+        .iter()
+        .copied()
+        .skip_while(|line| line.trim().is_empty())
+        .take_while(|line| is_comment(line))
+        .collect();
+
+    let mut text = strip_comment_markers(&above);
+    if !body.is_empty() {
+        text.push(String::new());
+        text.extend(strip_comment_markers(&body));
+    }
+
+    text.dedup_by(|line, previous| line.is_empty() && previous.is_empty());
+    let text = text.join("\n").trim().to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+// A top-level comment of a tutorial together with the code that follows it
+pub struct Segment {
+    // 1-based line where the code starts, or the comment when there's no code
+    pub line: usize,
+    pub comment: Vec<String>,
+    pub code: Vec<String>,
+}
+
+impl Segment {
+    pub fn defines(&self, function: &str) -> bool {
+        let needle = format!("fn {}(", function);
+        self.code.iter().any(|line| line.contains(&needle))
+    }
+}
+
+// Change of `{ }` nesting on a line of code, ignoring braces in strings, chars and comments
+pub(crate) fn brace_delta(line: &str, in_block_comment: &mut bool) -> i32 {
+    let chars: Vec<char> = line.chars().collect();
+    let mut delta = 0;
+    let mut i = 0;
+    let mut in_string = false;
+
+    while i < chars.len() {
+        let pair = (chars[i], chars.get(i + 1).copied().unwrap_or(' '));
+        if *in_block_comment {
+            if pair == ('*', '/') {
+                *in_block_comment = false;
+                i += 1;
+            }
+        } else if in_string {
+            match chars[i] {
+                '\\' => i += 1,
+                '"' => in_string = false,
+                _ => {}
+            }
+        } else {
+            match pair {
+                ('/', '/') => break,
+                ('/', '*') => {
+                    *in_block_comment = true;
+                    i += 1;
+                }
+                ('"', _) => in_string = true,
+                // A char literal like '{', but not a lifetime like 'a
+                ('\'', _) if chars.get(i + 2) == Some(&'\'') => i += 2,
+                ('{', _) => delta += 1,
+                ('}', _) => delta -= 1,
+                _ => {}
+            }
+        }
+        i += 1;
+    }
+    delta
+}
+
+// Splits a tutorial into segments, each one is an explanatory top-level comment and the code it
+// describes. Imports are left out because they don't teach anything.
+pub fn segments(source: &str) -> Vec<Segment> {
+    let mut segments: Vec<Segment> = Vec::new();
+    let mut comment_lines: Vec<&str> = Vec::new();
+    let mut depth = 0;
+    let mut in_block_comment = false;
+    let mut in_use = false;
+
+    for (index, line) in source.lines().enumerate() {
+        let trimmed = line.trim();
+        let top_level_comment = depth == 0 && (in_block_comment || is_comment(trimmed));
+
+        if top_level_comment {
+            if comment_lines.is_empty() {
+                segments.push(Segment {
+                    line: index + 1,
+                    comment: Vec::new(),
+                    code: Vec::new(),
+                });
+            }
+            comment_lines.push(line);
+            if trimmed.starts_with("/*") || in_block_comment {
+                in_block_comment = !trimmed.ends_with("*/");
+            }
+            continue;
+        }
+
+        if depth == 0 && trimmed.is_empty() && !comment_lines.is_empty() {
+            comment_lines.push(line);
+            continue;
+        }
+
+        if !comment_lines.is_empty() {
+            if let Some(segment) = segments.last_mut() {
+                segment.comment = strip_comment_markers(&comment_lines);
+            }
+            comment_lines.clear();
+        }
+        if segments.is_empty() {
+            segments.push(Segment {
+                line: index + 1,
+                comment: Vec::new(),
+                code: Vec::new(),
+            });
+        }
+
+        if depth == 0 && (in_use || trimmed.starts_with("use ")) {
+            in_use = !trimmed.ends_with(';');
+            continue;
+        }
+
+        depth += brace_delta(line, &mut in_block_comment);
+        if let Some(segment) = segments.last_mut() {
+            if segment.code.iter().all(|line| line.trim().is_empty()) && !trimmed.is_empty() {
+                segment.line = index + 1;
+            }
+            segment.code.push(line.trim_end().to_string());
+        }
+    }
+
+    if let Some(segment) = segments.last_mut() {
+        if !comment_lines.is_empty() {
+            segment.comment = strip_comment_markers(&comment_lines);
+        }
+    }
+
+    for segment in &mut segments {
+        trim_empty_lines(&mut segment.comment);
+        trim_empty_lines(&mut segment.code);
+    }
+    segments.retain(|segment| !segment.comment.is_empty() || !segment.code.is_empty());
+    segments
+}
+
+fn trim_empty_lines(lines: &mut Vec<String>) {
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+    let leading = lines
+        .iter()
+        .take_while(|line| line.trim().is_empty())
+        .count();
+    lines.drain(..leading);
+}
@@ -0,0 +1,173 @@
+// Tracing ownership
+//
+// Moving a value in Rust doesn't run any code, so a move can't be seen from the outside. Tracked<T>
+// wraps a value and writes what happens to it into a shared Timeline: when it's created and
+// cloned, when it's moved (through the explicit `moved_to` hook, since moves are invisible) and
+// when it's dropped, each step labelled with the scope the value lives in at that moment.
+
+use std::{
+    cell::RefCell,
+    fmt,
+    io::{self, Write},
+    ops::Deref,
+    rc::Rc,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventKind {
+    Created,
+    Cloned { from: String },
+    Moved { from: String },
+    Dropped,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    // Name of the variable that holds the value after this event
+    pub name: String,
+    pub scope: String,
+    pub kind: EventKind,
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            EventKind::Created => write!(f, "created {}", self.name),
+            EventKind::Cloned { from } => write!(f, "cloned {} into {}", from, self.name),
+            EventKind::Moved { from } => write!(f, "moved {} to {}", from, self.name),
+            EventKind::Dropped => write!(f, "dropped {}", self.name),
+        }
+    }
+}
+
+// Cloning a Timeline gives another handle to the same list of events
+#[derive(Debug, Clone, Default)]
+pub struct Timeline {
+    events: Rc<RefCell<Vec<Event>>>,
+}
+
+impl Timeline {
+    pub fn new() -> Self {
+        Timeline::default()
+    }
+
+    fn push(&self, name: &str, scope: &str, kind: EventKind) {
+        self.events.borrow_mut().push(Event {
+            name: name.to_string(),
+            scope: scope.to_string(),
+            kind,
+        });
+    }
+
+    pub fn events(&self) -> Vec<Event> {
+        self.events.borrow().clone()
+    }
+
+    // One numbered line per event:
+    //
+    //      1. main            created unsized_data
+    //      2. main            moved unsized_data to get_ownership
+    pub fn write(&self, out: &mut dyn Write) -> io::Result<()> {
+        let events = self.events.borrow();
+        let width = events
+            .iter()
+            .map(|event| event.scope.len())
+            .max()
+            .unwrap_or(0);
+        for (step, event) in events.iter().enumerate() {
+            writeln!(
+                out,
+                "{:>3}. {:<width$}  {}",
+                step + 1,
+                event.scope,
+                event,
+                width = width
+            )?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Tracked<T> {
+    value: T,
+    name: String,
+    scope: String,
+    timeline: Timeline,
+}
+
+impl<T> Tracked<T> {
+    pub fn new(timeline: &Timeline, name: &str, scope: &str, value: T) -> Self {
+        timeline.push(name, scope, EventKind::Created);
+        Tracked {
+            value,
+            name: name.to_string(),
+            scope: scope.to_string(),
+            timeline: timeline.clone(),
+        }
+    }
+
+    // Call it where the value moves, e.g. `let b = a.moved_to("b", "main");` or
+    // `take(a.moved_to("arg", "take"))`. The value keeps the new name and scope from then on.
+    pub fn moved_to(mut self, name: &str, scope: &str) -> Self {
+        let from = std::mem::replace(&mut self.name, name.to_string());
+        self.scope = scope.to_string();
+        self.timeline
+            .push(&self.name, &self.scope, EventKind::Moved { from });
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn scope(&self) -> &str {
+        &self.scope
+    }
+}
+
+impl<T: Clone> Tracked<T> {
+    // A clone with a name of its own, in the scope of the original
+    pub fn clone_as(&self, name: &str) -> Self {
+        self.timeline.push(
+            name,
+            &self.scope,
+            EventKind::Cloned {
+                from: self.name.clone(),
+            },
+        );
+        Tracked {
+            value: self.value.clone(),
+            name: name.to_string(),
+            scope: self.scope.clone(),
+            timeline: self.timeline.clone(),
+        }
+    }
+}
+
+// A plain .clone() is named after the original, e.g. "data.clone()"
+impl<T: Clone> Clone for Tracked<T> {
+    fn clone(&self) -> Self {
+        self.clone_as(&format!("{}.clone()", self.name))
+    }
+}
+
+impl<T> Deref for Tracked<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Tracked<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {:?}", self.name, self.value)
+    }
+}
+
+impl<T> Drop for Tracked<T> {
+    fn drop(&mut self) {
+        self.timeline
+            .push(&self.name, &self.scope, EventKind::Dropped);
+    }
+}
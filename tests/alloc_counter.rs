@@ -0,0 +1,117 @@
+// The counting allocator only counts once it is the global allocator, so this test binary
+// installs it the same way the practice_one binary does.
+
+use practice_one::alloc_counter::{self, CountingAllocator, Stats};
+use std::{collections::HashMap, hint::black_box};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn is_installed_in_this_binary() {
+    let _ = black_box(Box::new(1u8));
+    assert!(alloc_counter::is_installed());
+}
+
+#[test]
+fn stack_values_do_not_allocate() {
+    let (sum, stats) = alloc_counter::measure(|| {
+        let numbers = [1u64, 2, 3];
+        black_box(numbers).iter().sum::<u64>()
+    });
+    assert_eq!(sum, 6);
+    assert!(stats.is_empty(), "{}", stats);
+}
+
+#[test]
+fn string_from_allocates_its_bytes() {
+    // How many bytes std asks for is its own business, but it's at least the text
+    let (name, allocated) = alloc_counter::measure(|| String::from("ScriptSaga"));
+    assert!(allocated.allocations >= 1, "{}", allocated);
+    assert!(allocated.bytes_allocated >= name.len(), "{}", allocated);
+
+    let ((), freed) = alloc_counter::measure(move || drop(name));
+    assert!(freed.deallocations >= 1, "{}", freed);
+    assert!(
+        freed.bytes_freed >= allocated.live_bytes() as usize,
+        "{}",
+        freed
+    );
+    assert!(freed.live_bytes() < 0, "{}", freed);
+}
+
+#[test]
+fn moves_do_not_allocate_but_clones_do() {
+    let name = String::from("ScriptSaga");
+    let (moved, stats) = alloc_counter::measure(move || name);
+    assert!(stats.is_empty(), "{}", stats);
+
+    let (copy, stats) = alloc_counter::measure(|| moved.clone());
+    assert_eq!(stats.allocations, 1);
+    assert_eq!(copy, moved);
+}
+
+#[test]
+fn growing_a_vec_reallocates_and_with_capacity_does_not() {
+    let (numbers, stats) = alloc_counter::measure(|| {
+        let mut numbers = Vec::new();
+        for number in 0..100u32 {
+            numbers.push(number);
+        }
+        numbers
+    });
+    assert_eq!(stats.allocations, 1);
+    assert!(stats.reallocations > 0);
+    assert!(
+        stats.live_bytes() >= (numbers.len() * 4) as isize,
+        "{}",
+        stats
+    );
+
+    let (_, stats) = alloc_counter::measure(|| {
+        let mut numbers = Vec::with_capacity(100);
+        for number in 0..100u32 {
+            numbers.push(number);
+        }
+        numbers
+    });
+    assert_eq!((stats.allocations, stats.reallocations), (1, 0));
+
+    let (_, stats) = alloc_counter::measure(|| HashMap::<u32, u32>::with_capacity(10));
+    assert_eq!(stats.allocations, 1);
+}
+
+#[test]
+fn other_threads_are_not_counted() {
+    let ((), stats) = alloc_counter::measure(|| {
+        std::thread::scope(|scope| {
+            scope.spawn(|| black_box(vec![0u8; 1024]));
+        })
+    });
+    // Spawning allocates a little on this thread, the vector is allocated on the other one
+    assert!(stats.bytes_allocated < 1024, "{}", stats);
+}
+
+#[test]
+fn stats_subtract_and_display() {
+    let later = Stats {
+        allocations: 3,
+        reallocations: 1,
+        deallocations: 2,
+        bytes_allocated: 30,
+        bytes_freed: 20,
+    };
+    let earlier = Stats {
+        allocations: 2,
+        bytes_allocated: 20,
+        ..Stats::default()
+    };
+    let difference = later - earlier;
+    assert_eq!(difference.allocations, 1);
+    assert_eq!(difference.live_bytes(), -10);
+    assert_eq!(
+        difference.to_string(),
+        "1 allocation, 1 reallocation, 2 frees, 10 bytes allocated"
+    );
+    assert_eq!(difference.counts(), "1 allocation, 1 reallocation, 2 frees");
+}
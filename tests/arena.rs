@@ -0,0 +1,123 @@
+// Small sizes and no file or clock access, so these also run under Miri:
+// cargo +nightly miri test --test arena
+
+use practice_one::{
+    arena::Arena,
+    lessons::tut4::{self, Fan, ScriptSagaFollower},
+};
+use std::{cell::Cell, rc::Rc};
+
+// Counts its drops, to check the arena drops every value exactly once
+struct Tracked(Rc<Cell<usize>>);
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[test]
+fn references_stay_valid_while_chunks_grow() {
+    let arena = Arena::new();
+    let values: Vec<&mut usize> = (0..100).map(|value| arena.alloc(value)).collect();
+    assert!(arena.chunks() > 1);
+    assert_eq!(arena.len(), 100);
+
+    for (expected, value) in values.into_iter().enumerate() {
+        assert_eq!(*value, expected);
+        *value += 1;
+    }
+    assert_eq!(arena.into_vec(), (1..=100).collect::<Vec<usize>>());
+}
+
+#[test]
+fn extend_gives_one_contiguous_slice() {
+    let arena = Arena::with_capacity(2);
+    let first = arena.alloc(String::from("script"));
+    let slice = arena.alloc_extend(["a", "b", "c"].map(String::from));
+    let empty = arena.alloc_extend(Vec::new());
+
+    assert_eq!(slice, ["a", "b", "c"]);
+    assert!(empty.is_empty());
+    slice[0].push_str("da");
+    first.push_str("_saga");
+    assert_eq!(arena.into_vec(), ["script_saga", "ada", "b", "c"]);
+}
+
+#[test]
+fn extend_can_allocate_in_the_same_arena() {
+    let arena = Arena::new();
+    let slice = arena.alloc_extend((0..3).map(|value| *arena.alloc(value * 10) + 1));
+    assert_eq!(slice, [1, 11, 21]);
+    assert_eq!(arena.len(), 6);
+}
+
+#[test]
+fn every_value_is_dropped_once() {
+    let drops = Rc::new(Cell::new(0));
+    {
+        let arena = Arena::new();
+        for _ in 0..20 {
+            arena.alloc(Tracked(Rc::clone(&drops)));
+        }
+        arena.alloc_extend((0..5).map(|_| Tracked(Rc::clone(&drops))));
+        assert_eq!(drops.get(), 0);
+    }
+    assert_eq!(drops.get(), 25);
+
+    let arena = Arena::new();
+    arena.alloc(Tracked(Rc::clone(&drops)));
+    let values = arena.into_vec();
+    assert_eq!(drops.get(), 25);
+    drop(values);
+    assert_eq!(drops.get(), 26);
+}
+
+#[test]
+fn zero_sized_values() {
+    let arena = Arena::new();
+    for _ in 0..10 {
+        arena.alloc(());
+    }
+    assert_eq!(arena.alloc_extend([(), ()]).len(), 2);
+    assert_eq!(arena.len(), 12);
+    assert_eq!(arena.chunks(), 1);
+}
+
+#[test]
+fn followers_borrow_names_from_another_arena() {
+    let names = Arena::new();
+    let followers = Arena::new();
+    let mut all = Vec::new();
+    for index in 0..10 {
+        let first_name = names.alloc(format!("follower_{}", index));
+        all.push(&*followers.alloc(ScriptSagaFollower::new(first_name, "saga".to_string())));
+    }
+
+    assert_eq!(all[7].first_name(), "follower_7");
+    let chosen = tut4::execute_life_time(all[0].first_name(), all[9].last_name());
+    assert_eq!(chosen, "follower_0");
+}
+
+#[test]
+fn fans_can_follow_each_other() {
+    let followers = Arena::new();
+    let fans = Arena::new();
+    let script = followers.alloc(ScriptSagaFollower::new("script", "saga".to_string()));
+    let ferris = followers.alloc(ScriptSagaFollower::new("ferris", "crab".to_string()));
+
+    let script_fan: &Fan = fans.alloc(Fan {
+        follower: script,
+        follows: Cell::new(None),
+    });
+    let ferris_fan: &Fan = fans.alloc(Fan {
+        follower: ferris,
+        follows: Cell::new(Some(script_fan)),
+    });
+    script_fan.follows.set(Some(ferris_fan));
+
+    let all = [script_fan, ferris_fan];
+    assert_eq!(tut4::name_of_followed(&all, 0), Some("ferris"));
+    assert_eq!(tut4::name_of_followed(&all, 1), Some("script"));
+    assert_eq!(tut4::name_of_followed(&all, 2), None);
+}
@@ -0,0 +1,311 @@
+use practice_one::{borrows, lessons::tut3, Error};
+use std::{
+    env, fs,
+    io::Write,
+    path::PathBuf,
+    process::{self, Command, Stdio},
+};
+
+fn codes(source: &str) -> Vec<(usize, &'static str)> {
+    borrows::analyse(source)
+        .unwrap()
+        .conflicts
+        .iter()
+        .map(|conflict| (conflict.line, conflict.code))
+        .collect()
+}
+
+#[test]
+fn borrows_end_at_their_last_use() {
+    let analysis = borrows::analyse(&tut3::borrow_rules_snippet(false)).unwrap();
+    assert!(analysis.is_accepted());
+
+    let ranges: Vec<(usize, usize)> = analysis
+        .loans
+        .iter()
+        .map(|loan| (loan.created, loan.end))
+        .collect();
+    assert_eq!(ranges, [(2, 4), (3, 3), (5, 5)]);
+    assert_eq!(
+        analysis.loans[0].describe(),
+        "immutable_borrow_arg1_first = &arg1"
+    );
+    assert_eq!(analysis.loans_live_on(3).count(), 2);
+}
+
+#[test]
+fn mutable_borrow_while_shared_is_live() {
+    assert_eq!(codes(&tut3::borrow_rules_snippet(true)), [(4, "E0502")]);
+}
+
+#[test]
+fn the_snippet_is_read_from_the_lesson() {
+    assert_eq!(
+        tut3::borrow_rules_snippet(false),
+        "\
+let mut arg1: String = String::from(\"follow_script_saga\");
+let immutable_borrow_arg1_first = &arg1;
+let immutable_borrow_arg1_second = &arg1;
+println!(\"{}\", immutable_borrow_arg1_first);
+let mutable_borrow_arg1 = &mut arg1;
+"
+    );
+    let conflict = tut3::borrow_rules_snippet(true);
+    assert_eq!(
+        conflict.lines().nth(3),
+        Some("let mutable_borrow_arg1 = &mut arg1;")
+    );
+    assert_eq!(conflict.lines().count(), 6);
+}
+
+#[test]
+fn agrees_with_the_compile_fail_fixture() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/compile-fail/tut3_mutable_and_immutable_borrow.rs");
+    let source = fs::read_to_string(path).unwrap();
+    assert!(source.starts_with("// error-code: E0502"));
+    assert_eq!(codes(&source), [(10, "E0502")]);
+}
+
+#[test]
+fn each_rule_is_reported_with_its_code() {
+    let source = "\
+let r;
+{
+    let x = 5;
+    r = &x;
+}
+println!(\"{}\", r);
+let mut v = String::new();
+let m = &mut v;
+let n = &mut v;
+m.push_str(\"a\");
+let s = &v;
+v = String::new();
+take(v);
+println!(\"{}\", s);
+let mut count = 1;
+let c = &mut count;
+let d = count + 1;
+*c = 2;
+let fixed = 1;
+let f = &mut fixed;";
+    assert_eq!(
+        codes(source),
+        [
+            (5, "E0597"),
+            (9, "E0499"),
+            (12, "E0506"),
+            (13, "E0505"),
+            (17, "E0503"),
+            (20, "E0596"),
+        ]
+    );
+}
+
+#[test]
+fn copies_and_calls_pass_borrows_on() {
+    let source = "\
+let s = String::new();
+let t = &s;
+let u = t;
+let w = first(u);
+println!(\"{}\", w);
+drop(s);";
+    let analysis = borrows::analyse(source).unwrap();
+    assert!(analysis.is_accepted(), "{}", analysis);
+    assert_eq!(analysis.loans[0].holders, ["t", "u", "w"]);
+    assert_eq!(analysis.loans[0].end, 5);
+
+    // Moving s before the last use of w isn't allowed
+    let moved_early = source.replace("println!(\"{}\", w);\ndrop(s);", "drop(s);\nw.len();");
+    assert_eq!(codes(&moved_early), [(5, "E0505")]);
+}
+
+#[test]
+fn temporaries_only_last_for_their_line() {
+    let source = "\
+let mut name = String::new();
+push(&mut name, 1);
+print(&name);
+push(&mut name, &name);";
+    let analysis = borrows::analyse(source).unwrap();
+    assert!(analysis.loans[..2]
+        .iter()
+        .all(|loan| loan.end == loan.created));
+    assert!(analysis.loans[0].holders.is_empty());
+    assert_eq!(codes(source), [(4, "E0502")]);
+}
+
+#[test]
+fn function_parameters_are_declared() {
+    let source = "\
+fn longest<'a>(first: &'a str, mut second: String) -> &'a str {
+    let r = &mut second;
+    let s = first;
+    r.push_str(s);
+}";
+    assert!(borrows::analyse(source).unwrap().is_accepted());
+}
+
+#[test]
+fn timeline_marks_starts_uses_and_errors() {
+    let rendered = borrows::analyse(&tut3::borrow_rules_snippet(true))
+        .unwrap()
+        .to_string();
+    assert!(rendered.contains("2 | o       | let immutable_borrow_arg1_first = &arg1;"));
+    assert!(
+        rendered.contains("4 | |   o   | let mutable_borrow_arg1 = &mut arg1;  // error[E0502]")
+    );
+    assert!(rendered.contains("5 | *       | println!"));
+    assert!(rendered.contains("a borrows `arg1` on line 2 and is used until line 5"));
+}
+
+#[test]
+fn using_a_moved_value_is_reported() {
+    let source = "\
+let s = String::new();
+let t = s;
+let u = s;
+println!(\"{}\", s);
+s = String::new();
+let v = &s;";
+    let analysis = borrows::analyse(source).unwrap();
+    assert_eq!(codes(source), [(3, "E0382"), (4, "E0382")]);
+    assert_eq!(
+        analysis.conflicts[1].message,
+        "borrow of moved value: `s`, it was moved on line 2"
+    );
+    assert!(!analysis.to_string().contains("no conflicts"));
+
+    // Copy values and primitive parameters can be used again
+    let source = "\
+fn count(n: i32, name: String) {
+    let a = n;
+    let b = n;
+    let c = 1;
+    let d = c;
+    let e = c;
+    take(name);
+}";
+    assert!(borrows::analyse(source).unwrap().is_accepted());
+    assert_eq!(
+        codes(&source.replace("take(name);", "take(name);\ntake(name);\n")),
+        [(8, "E0382")]
+    );
+}
+
+#[test]
+fn method_calls_on_mut_variables_borrow_them_mutably() {
+    // push needs &mut v while r still borrows it
+    let source = "\
+let mut v = Vec::new();
+let r = &v;
+v.push(1);
+println!(\"{:?}\", r);";
+    assert_eq!(codes(source), [(3, "E0502")]);
+    let analysis = borrows::analyse(source).unwrap();
+    assert!(analysis.to_string().contains(
+        "error[E0502] on line 3: cannot borrow `v` as mutable because it is also borrowed as immutable"
+    ));
+
+    // Once r is done with, or when v isn't mut, the call is fine
+    assert!(
+        codes("let mut v = Vec::new();\nlet r = &v;\nprintln!(\"{:?}\", r);\nv.push(1);")
+            .is_empty()
+    );
+    assert!(
+        codes("let v = String::new();\nlet r = &v;\nv.len();\nprintln!(\"{:?}\", r);").is_empty()
+    );
+}
+
+#[test]
+fn chars_and_question_marks_are_understood() {
+    let source = "\
+let mut line = String::new();
+line.push('\\n');
+line.push('\\'');
+line.push('\\\\');
+line.push('\\u{1F980}');
+let first = line.chars().next()?;
+read(&mut line)?.len();";
+    assert!(borrows::analyse(source).unwrap().is_accepted());
+}
+
+#[test]
+fn parse_errors_point_at_the_column() {
+    for (source, line, column) in [
+        ("let x = 1;\nlet y = [x];", 2, 9),
+        ("let x = 1; let y = 2;", 1, 12),
+        ("let r = &missing;", 1, 10),
+        ("let s = \"open;", 1, 9),
+        ("let c = '\\u{1F980;", 1, 9),
+        ("let = 1;", 1, 5),
+        ("let x = ;", 1, 9),
+        ("let x = 1\n  }", 2, 3),
+        ("let x: Vec<u8 = Vec::new();", 1, 11),
+        ("fn f(x: Option<(u8, u8>) {", 1, 16),
+    ] {
+        match borrows::analyse(source) {
+            Err(Error::Parse {
+                line: actual_line,
+                column: actual_column,
+                ..
+            }) => assert_eq!(
+                (actual_line, actual_column),
+                (line, Some(column)),
+                "{}",
+                source
+            ),
+            other => panic!("{:?} gave {:?}", source, other),
+        }
+    }
+}
+
+#[test]
+fn unsupported_code_is_a_parse_error() {
+    for (source, line) in [
+        ("let x = 1;\nlet y = [x];", 2),
+        ("let x = 1; let y = 2;", 1),
+        ("let r = &missing;", 1),
+        ("{\nlet x = 1;", 2),
+        ("}", 1),
+        ("if x {", 1),
+    ] {
+        match borrows::analyse(source) {
+            Err(Error::Parse { line: actual, .. }) => assert_eq!(actual, line, "{}", source),
+            other => panic!("{:?} gave {:?}", source, other),
+        }
+    }
+}
+
+#[test]
+fn errors_in_a_snippet_from_stdin_name_stdin() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_practice_one"))
+        .args(["borrows", "-"])
+        .env(
+            "PRACTICE_ONE_PROGRESS",
+            env::temp_dir().join(format!("practice_one_borrows_{}", process::id())),
+        )
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to run practice_one");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(b"let x: Vec<u8 = Vec::new();\n")
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("error: <stdin>\n"), "{}", stderr);
+    assert!(
+        stderr.contains("line 1, column 11: `<` is never closed"),
+        "{}",
+        stderr
+    );
+}
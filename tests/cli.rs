@@ -0,0 +1,94 @@
+// Picking lessons from the command line
+
+use practice_one::Registry;
+use std::{
+    env,
+    process::{self, Command},
+};
+
+// Exit status, stdout and stderr of a practice_one run
+fn practice_one(name: &str, args: &[&str]) -> (bool, String, String) {
+    let progress = env::temp_dir().join(format!("practice_one_cli_{}_{}", process::id(), name));
+    let output = Command::new(env!("CARGO_BIN_EXE_practice_one"))
+        .args(args)
+        .env("PRACTICE_ONE_PROGRESS", &progress)
+        .output()
+        .expect("failed to run practice_one");
+    std::fs::remove_file(&progress).ok();
+    (
+        output.status.success(),
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+        String::from_utf8_lossy(&output.stderr).into_owned(),
+    )
+}
+
+#[test]
+fn list_shows_every_lesson_under_its_tutorial() {
+    let (ok, out, _) = practice_one("list", &["list"]);
+    assert!(ok);
+    assert!(out.starts_with("tut2\n    result "));
+    assert!(out.contains("tut10\n    hash_map "));
+
+    let (_, ids, _) = practice_one("ids", &["list", "--ids"]);
+    let registry = Registry::with_all_lessons();
+    let expected: Vec<String> = registry
+        .lessons()
+        .map(|lesson| lesson.qualified_id())
+        .collect();
+    assert_eq!(ids.lines().collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn a_tutorial_can_be_named_in_several_ways() {
+    for tutorial in ["tut2", "2", "TUT2"] {
+        let (ok, out, _) = practice_one("tutorial", &[tutorial]);
+        assert!(ok, "{}", tutorial);
+        assert!(out.starts_with("== tut2::result ==\n"), "{}", tutorial);
+        assert!(out.contains("== tut2::option =="), "{}", tutorial);
+    }
+}
+
+#[test]
+fn a_lesson_runs_by_tutorial_and_name_or_by_a_unique_name() {
+    let (ok, out, _) = practice_one("lesson", &["tut2", "result"]);
+    assert!(ok);
+    assert_eq!(out.lines().next(), Some("== tut2::result =="));
+
+    let (ok, alone, _) = practice_one("unique", &["result"]);
+    assert!(ok);
+    assert_eq!(alone, out);
+}
+
+#[test]
+fn unknown_names_list_what_there_is() {
+    let (ok, _, err) = practice_one("unknown_lesson", &["tut2", "nothing"]);
+    assert!(!ok);
+    assert!(err.starts_with("error: unknown lesson `tut2::nothing`, available: result, option"));
+
+    let (ok, _, err) = practice_one("unknown_tutorial", &["tut99", "result"]);
+    assert!(!ok);
+    assert!(err.starts_with("error: unknown tutorial `tut99`, available: tut2, tut3"));
+
+    let (ok, _, err) = practice_one("unknown_name", &["nothing"]);
+    assert!(!ok);
+    assert!(err.starts_with("error: unknown lesson or tutorial `nothing`"));
+}
+
+#[test]
+fn search_needs_a_query() {
+    let (ok, out, err) = practice_one("search", &["search"]);
+    assert!(!ok);
+    assert!(out.is_empty());
+    assert_eq!(
+        err,
+        "error: search needs a query, e.g. `practice_one search borrow`\n"
+    );
+}
+
+#[test]
+fn too_many_arguments_show_the_usage() {
+    let (ok, _, err) = practice_one("too_many", &["tut2", "result", "again", "and again"]);
+    assert!(!ok);
+    assert!(err.starts_with("error: too many arguments\n"));
+    assert!(err.contains("practice_one list"));
+}
@@ -0,0 +1,55 @@
+// The Option and Result helpers of tut2, called the way another crate would call them
+
+use practice_one::{lessons::tut2, Error};
+
+#[test]
+fn map_and_then_and_ok_or_else_keep_the_ok_value() {
+    assert!(matches!(tut2::doubled_result(), Ok(2)));
+    assert!(matches!(tut2::option_or_error(), Ok(1)));
+    assert!(matches!(tut2::hundred_divided_by_result(), Ok(100)));
+}
+
+#[test]
+fn parse_follower_count_rejects_bad_input() {
+    assert!(matches!(tut2::parse_follower_count(" 7 "), Ok(7)));
+    assert!(matches!(
+        tut2::parse_follower_count("seven"),
+        Err(Error::Validation(_))
+    ));
+    assert!(matches!(
+        tut2::parse_follower_count("-1"),
+        Err(Error::Validation(message)) if message.contains("negative")
+    ));
+}
+
+#[test]
+fn transpose_keeps_missing_input_apart_from_bad_input() {
+    assert!(matches!(tut2::parse_optional_count(None), Ok(None)));
+    assert!(matches!(tut2::parse_optional_count(Some("3")), Ok(Some(3))));
+    assert!(tut2::parse_optional_count(Some("x")).is_err());
+}
+
+#[test]
+fn collect_returns_the_first_error() {
+    assert_eq!(tut2::parse_all_counts(&["4", "5"]).unwrap(), vec![4, 5]);
+    assert!(tut2::parse_all_counts(&[]).unwrap().is_empty());
+    assert!(matches!(
+        tut2::parse_all_counts(&["4", "x", "-1"]),
+        Err(Error::Validation(message)) if message.contains("`x`")
+    ));
+}
+
+#[test]
+fn total_followers_stops_at_the_first_failure() {
+    assert!(matches!(tut2::total_followers(&["10", "20"]), Ok(32)));
+    assert!(tut2::total_followers(&["10", "oops"]).is_err());
+    assert!(tut2::total_followers(&[&i32::MAX.to_string()]).is_err());
+}
+
+#[test]
+fn lessons_run_their_assertions() {
+    let mut out = Vec::new();
+    tut2::understanding_combinators(&mut out).unwrap();
+    tut2::understanding_error_propagation(&mut out).unwrap();
+    assert!(String::from_utf8(out).unwrap().contains("? chain: Ok(32)"));
+}
@@ -0,0 +1,14 @@
+// error-code: E0502
+// tut3::understand_borrow_rules - borrowing mutably while an immutable borrow is still in use
+
+pub fn understand_borrow_rules() {
+    let mut arg1: String = String::from("follow_script_saga");
+
+    let immutable_borrow_arg1_first = &arg1;
+    let immutable_borrow_arg1_second = &arg1;
+
+    let mutable_borrow_arg1 = &mut arg1;
+
+    println!("{}", immutable_borrow_arg1_first);
+    println!("{} {}", immutable_borrow_arg1_second, mutable_borrow_arg1);
+}
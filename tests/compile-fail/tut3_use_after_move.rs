@@ -0,0 +1,9 @@
+// error-code: E0382
+// tut3::understand_ownership - the String is moved, so the old variable is not valid anymore
+
+pub fn understand_ownership() {
+    let unsized_data = String::from("ScriptSaga");
+    let get_ownership = unsized_data;
+
+    println!("This will give a move error: {} {}", unsized_data, get_ownership);
+}
@@ -0,0 +1,18 @@
+// error-code: E0597
+// tut4::script_saga - arg2 only lives inside the inner block, so the returned reference, tied to
+// both arguments by 'a, can't be used after it
+
+pub fn script_saga() {
+    let arg1 = String::from("script");
+    let picked;
+    {
+        let arg2 = String::from("saga");
+        picked = execute_life_time(&arg1, &arg2);
+    }
+
+    println!("{}", picked);
+}
+
+pub fn execute_life_time<'a>(arg1: &'a str, arg2: &'a str) -> &'a str {
+    arg1
+}
@@ -0,0 +1,7 @@
+// error-code: E0106
+// tut4 - with several reference parameters the elision rules can't pick the returned lifetime
+
+pub fn function_with_incorrect_return_ref(arg1: &str, arg2: &bool, arg3: &str) -> &str {
+    let a = "script_saga";
+    &arg1
+}
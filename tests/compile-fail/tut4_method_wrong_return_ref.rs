@@ -0,0 +1,14 @@
+// error-message: lifetime may not live long enough
+// tut4::ScriptSagaFollower - `self` is taken by value, so the returned reference gets the lifetime
+// of `arg1` instead of 'a
+
+pub struct ScriptSagaFollower<'a> {
+    first_name: &'a str,
+    last_name: String,
+}
+
+impl<'a> ScriptSagaFollower<'a> {
+    pub fn method_with_self_wrong_return_ref<'b>(self, arg1: &'b str) -> &str {
+        self.first_name
+    }
+}
@@ -0,0 +1,7 @@
+// error-code: E0106
+// tut4 - references stored in a struct need a lifetime
+
+pub struct WrongScriptSagaFollower {
+    first_name: &str,
+    last_name: &str,
+}
@@ -0,0 +1,9 @@
+// error-code: E0308
+// tut6::understanding_if_else - an if condition has to be a bool
+
+pub fn understanding_if_else() {
+    let value: Option<i32> = None;
+    if value {
+        // Execute some code.
+    }
+}
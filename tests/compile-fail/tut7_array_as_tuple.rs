@@ -0,0 +1,7 @@
+// error-code: E0308
+// tut7 - an array can't be destructured with a tuple pattern
+
+pub fn understanding_rust_destructuring_with_pattern_matching() {
+    let arr_1: [i32; 2] = [1, 2];
+    let (a, b) = arr_1;
+}
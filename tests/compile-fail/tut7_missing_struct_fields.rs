@@ -0,0 +1,13 @@
+// error-code: E0027
+// tut7 - every field has to be named while destructuring, or skipped with ..
+
+pub struct Coordinate {
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+pub fn understanding_rust_destructuring_with_pattern_matching() {
+    let coordinate_one = Coordinate { x: 0, y: 0, z: 0 };
+    let Coordinate { x } = coordinate_one;
+}
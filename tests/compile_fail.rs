@@ -0,0 +1,89 @@
+// Compile-fail checks for the "this is wrong" examples of the tutorials
+//
+// Every file in tests/compile-fail is fed to the local rustc and must fail to compile. The first
+// lines of a fixture say how it has to fail:
+//
+//     // error-code: E0106                                  rustc reports error[E0106]
+//     // error-message: lifetime may not live long enough   for errors that come without a code
+
+use std::{env, fs, path::PathBuf, process::Command};
+
+enum Expectation {
+    Code(String),
+    Message(String),
+}
+
+fn expectation(source: &str) -> Option<Expectation> {
+    source
+        .lines()
+        .take_while(|line| line.starts_with("//"))
+        .find_map(|line| {
+            let line = line.trim_start_matches('/').trim();
+            if let Some(code) = line.strip_prefix("error-code:") {
+                Some(Expectation::Code(code.trim().to_string()))
+            } else {
+                line.strip_prefix("error-message:")
+                    .map(|message| Expectation::Message(message.trim().to_string()))
+            }
+        })
+}
+
+#[test]
+fn wrong_examples_fail_with_the_expected_error() {
+    let fixtures = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/compile-fail");
+    let out_dir = env::temp_dir().join(format!("practice_one-compile-fail-{}", std::process::id()));
+    fs::create_dir_all(&out_dir).unwrap();
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+
+    let mut paths: Vec<PathBuf> = fs::read_dir(&fixtures)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "rs"))
+        .collect();
+    paths.sort();
+    assert!(!paths.is_empty(), "no fixtures in {}", fixtures.display());
+
+    let mut failures = Vec::new();
+    for path in &paths {
+        let name = path.file_name().unwrap().to_string_lossy();
+        let source = fs::read_to_string(path).unwrap();
+        let Some(expected) = expectation(&source) else {
+            failures.push(format!("{}: missing an `// error-code:` header", name));
+            continue;
+        };
+
+        let output = Command::new(&rustc)
+            .args([
+                "--edition",
+                "2021",
+                "--crate-type",
+                "lib",
+                "--emit=metadata",
+            ])
+            .args(["--error-format", "short", "--out-dir"])
+            .arg(&out_dir)
+            .arg(path)
+            .output()
+            .expect("failed to run rustc");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        if output.status.success() {
+            failures.push(format!("{}: compiled, but it should fail", name));
+            continue;
+        }
+
+        let (found, wanted) = match &expected {
+            Expectation::Code(code) => (stderr.contains(&format!("error[{}]", code)), code),
+            Expectation::Message(message) => (stderr.contains(message.as_str()), message),
+        };
+        if !found {
+            failures.push(format!(
+                "{}: expected `{}`, rustc said:\n{}",
+                name, wanted, stderr
+            ));
+        }
+    }
+
+    fs::remove_dir_all(&out_dir).ok();
+    assert!(failures.is_empty(), "{}", failures.join("\n\n"));
+}
@@ -0,0 +1,95 @@
+// Markdown chapters generated from the tutorial sources
+
+use practice_one::{docs, segments, Registry};
+use std::{env, fs, process};
+
+#[test]
+fn chapters_turn_comments_into_text_and_code_into_blocks() {
+    let registry = Registry::with_all_lessons();
+    let chapter = docs::chapter(&registry, 10);
+
+    assert!(chapter.starts_with("# Tutorial 10\n\nLessons in this chapter:\n\n"));
+    assert!(chapter.contains("- Understanding hash maps (`practice_one tut10 hash_map`)"));
+    assert!(chapter.contains("## Understanding Rust Hash Maps\n"));
+    assert!(chapter.contains("```rust\npub fn understanding_hash_map(out: &mut dyn Write)"));
+    assert!(chapter.contains("_src/lessons/tut10.rs, line "));
+    assert!(chapter.contains("![hash map.png](images/hash%20map.png)"));
+    assert!(chapter.ends_with("\n") && !chapter.ends_with("\n\n"));
+}
+
+#[test]
+fn the_lesson_runner_plumbing_is_left_out() {
+    let registry = Registry::with_all_lessons();
+    for tutorial in registry.tutorials() {
+        let chapter = docs::chapter(&registry, tutorial);
+        assert!(!chapter.contains("fn register("), "tut{}", tutorial);
+        assert!(!chapter.contains("fn layouts("), "tut{}", tutorial);
+        assert!(!chapter.contains("struct_layout!"), "tut{}", tutorial);
+        assert!(
+            !chapter.contains("fn borrow_rules_snippet("),
+            "tut{}",
+            tutorial
+        );
+        assert!(!chapter.contains("use crate::"), "tut{}", tutorial);
+    }
+}
+
+#[test]
+fn segments_start_at_their_code() {
+    let source = "\
+use std::io;
+
+// Heading
+
+// Text about
+// the code
+pub fn lesson() {
+    // inside the body
+    let braces = '{';
+}
+
+/*
+    A block comment
+*/
+const LAST: u8 = 1;
+";
+    let segments = segments(source);
+    assert_eq!(segments.len(), 2);
+    // Comments only separated by blank lines belong together, the first line reads as a heading
+    assert_eq!(
+        segments[0].comment,
+        ["Heading", "", "Text about", "the code"]
+    );
+    assert_eq!(segments[0].line, 7);
+    assert_eq!(segments[0].code.len(), 4);
+    assert!(segments[0].defines("lesson"));
+    assert!(!segments[0].defines("less"));
+    assert_eq!(segments[1].comment, ["A block comment"]);
+    assert_eq!(segments[1].code, ["const LAST: u8 = 1;"]);
+}
+
+#[test]
+fn the_table_of_contents_links_every_chapter() {
+    let registry = Registry::with_all_lessons();
+    let contents = docs::table_of_contents(&registry);
+    for tutorial in registry.tutorials() {
+        let link = format!("- [Tutorial {0}](tut{0}.md)\n", tutorial);
+        assert!(contents.contains(&link), "{}", link);
+    }
+    assert!(contents.contains("    - strings: Understanding strings\n"));
+}
+
+#[test]
+fn generate_writes_every_file() {
+    let registry = Registry::with_all_lessons();
+    let out_dir = env::temp_dir().join(format!("practice_one_docs_{}", process::id()));
+    let written = docs::generate(&registry, &out_dir).unwrap();
+
+    assert_eq!(written.len(), registry.tutorials().len() + 1);
+    assert!(written.iter().all(|path| path.is_file()));
+    assert!(out_dir.join("images/hash map.png").is_file());
+    let readme = fs::read_to_string(out_dir.join("README.md")).unwrap();
+    assert_eq!(readme, docs::table_of_contents(&registry));
+
+    fs::remove_dir_all(&out_dir).unwrap();
+}
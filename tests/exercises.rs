@@ -0,0 +1,65 @@
+// The exercises and their hidden tests
+
+use practice_one::exercises::{self, CheckReport, TestResult};
+
+fn results(report: CheckReport, name: &str) -> Vec<TestResult> {
+    match report {
+        CheckReport::Ran(results) => results,
+        CheckReport::CompileError(output) => panic!("{} does not compile:\n{}", name, output),
+    }
+}
+
+#[test]
+fn every_tutorial_has_an_exercise() {
+    let registry = practice_one::Registry::with_all_lessons();
+    for tutorial in registry.tutorials() {
+        assert!(
+            exercises::EXERCISES
+                .iter()
+                .any(|exercise| exercise.tutorial == tutorial),
+            "tut{} has no exercise",
+            tutorial
+        );
+    }
+}
+
+#[test]
+fn reference_solutions_pass_every_hidden_test() {
+    for exercise in exercises::EXERCISES {
+        let results = results(exercises::check_solution(exercise).unwrap(), exercise.name);
+        assert!(!results.is_empty(), "{} ran no tests", exercise.name);
+        for result in &results {
+            assert!(result.passed, "{}: {} fails", exercise.name, result.name);
+        }
+    }
+}
+
+#[test]
+fn stubs_compile_and_fail_with_hints() {
+    for exercise in exercises::EXERCISES {
+        let results = results(exercises::check(exercise).unwrap(), exercise.name);
+        assert!(
+            results.iter().any(|result| !result.passed),
+            "{} already passes before it's done",
+            exercise.name
+        );
+        for result in &results {
+            assert!(
+                result.hint.is_some(),
+                "{}: {} has no hint",
+                exercise.name,
+                result.name
+            );
+        }
+    }
+}
+
+#[test]
+fn exercises_are_found_by_name() {
+    let exercise = exercises::find("tut9_role").unwrap();
+    assert_eq!(exercise.tutorial, 9);
+    assert!(exercises::exercise_dir(exercise)
+        .join("solution.rs")
+        .is_file());
+    assert!(exercises::find("tut1_nothing").is_none());
+}
@@ -0,0 +1,121 @@
+// The file helpers of tut11, with the NotFound, AlreadyExists and PermissionDenied cases created
+// for real in a temp directory. Every test uses its own directory so they can run in parallel.
+
+use practice_one::lessons::tut11::{self, Recovery};
+use std::{
+    fs,
+    io::{self, ErrorKind},
+    path::PathBuf,
+};
+
+// Removes the directory when the test ends, also when it fails
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> TempDir {
+        TempDir(tut11::lesson_dir(&format!("test-{}", name)).unwrap())
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        tut11::set_writable(&self.0.join("locked"), true).ok();
+        fs::remove_dir_all(&self.0).ok();
+    }
+}
+
+#[test]
+fn write_append_read_and_list() {
+    let dir = TempDir::new("basics");
+    let notes = dir.0.join("notes.txt");
+
+    tut11::write_file(&notes, "one\n").unwrap();
+    tut11::append_line(&notes, "two").unwrap();
+    tut11::append_line(&dir.0.join("appended.txt"), "created by append").unwrap();
+    fs::create_dir(dir.0.join("sub")).unwrap();
+
+    assert_eq!(tut11::read_file(&notes).unwrap(), "one\ntwo\n");
+    assert_eq!(
+        tut11::list_files(&dir.0).unwrap(),
+        ["appended.txt", "notes.txt"]
+    );
+}
+
+#[test]
+fn not_found_creates_the_default() {
+    let dir = TempDir::new("not-found");
+    let settings = dir.0.join("settings.txt");
+
+    let error = tut11::read_file(&settings).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::NotFound);
+    assert_eq!(tut11::recovery_for(&error), Recovery::CreateWithDefault);
+
+    assert_eq!(tut11::read_or_create(&settings, "a=1").unwrap(), "a=1");
+    // The second time the file is there and its contents win
+    fs::write(&settings, "a=2").unwrap();
+    assert_eq!(tut11::read_or_create(&settings, "a=1").unwrap(), "a=2");
+}
+
+#[test]
+fn already_exists_keeps_the_file() {
+    let dir = TempDir::new("already-exists");
+    let notes = dir.0.join("notes.txt");
+
+    assert!(tut11::create_or_keep(&notes, "first").unwrap());
+    assert!(!tut11::create_or_keep(&notes, "second").unwrap());
+    assert_eq!(fs::read_to_string(&notes).unwrap(), "first");
+}
+
+#[test]
+fn permission_denied_writes_to_the_fallback() {
+    let dir = TempDir::new("permission-denied");
+    let locked = dir.0.join("locked");
+    fs::create_dir(&locked).unwrap();
+    tut11::set_writable(&locked, false).unwrap();
+
+    let target = locked.join("notes.txt");
+    let fallback = dir.0.join("fallback.txt");
+    match fs::write(&target, "probe") {
+        Err(error) => {
+            assert_eq!(error.kind(), ErrorKind::PermissionDenied);
+            assert_eq!(tut11::recovery_for(&error), Recovery::WriteElsewhere);
+
+            let written = tut11::save_or_fallback(&target, &fallback, "text").unwrap();
+            assert_eq!(written, fallback);
+            assert_eq!(fs::read_to_string(&fallback).unwrap(), "text");
+        }
+        // Permissions don't stop root, so the error can't be provoked here
+        Ok(()) => eprintln!("skipping the PermissionDenied check, running with elevated rights"),
+    }
+}
+
+#[test]
+fn other_errors_are_given_up_on() {
+    let dir = TempDir::new("other");
+    let binary = dir.0.join("binary.dat");
+    fs::write(&binary, [0xff]).unwrap();
+
+    let error = tut11::read_file(&binary).unwrap_err();
+    assert_eq!(tut11::recovery_for(&error), Recovery::GiveUp);
+
+    // save_or_fallback doesn't hide errors it can't recover from
+    let missing_dir = dir.0.join("missing").join("notes.txt");
+    let error =
+        tut11::save_or_fallback(&missing_dir, &dir.0.join("fallback.txt"), "text").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::NotFound);
+    assert!(!dir.0.join("fallback.txt").exists());
+
+    assert_eq!(
+        tut11::recovery_for(&io::Error::from(ErrorKind::TimedOut)),
+        Recovery::GiveUp
+    );
+}
+
+#[test]
+fn the_lesson_cleans_up_after_itself() {
+    let mut out = Vec::new();
+    tut11::understanding_file_io(&mut out).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    assert!(out.contains("PermissionDenied -> WriteElsewhere"));
+    assert!(!tut11::lesson_dir_path("tut11").exists());
+}
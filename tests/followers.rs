@@ -0,0 +1,144 @@
+use practice_one::{
+    alloc_counter::{self, CountingAllocator},
+    followers::{self, Followers, OwnedFollower},
+    lessons::tut4::ScriptSagaFollower,
+    Error,
+};
+
+// To check that borrowing followers doesn't allocate
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const LIST: &str = "\
+# first name, last name
+script, saga
+
+ferris,crab
+  grace , hopper  
+";
+
+fn location(error: Error) -> (usize, Option<usize>, String) {
+    match error {
+        Error::Parse {
+            line,
+            column,
+            message,
+        } => (line, column, message),
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+}
+
+#[test]
+fn followers_borrow_their_names_from_the_text() {
+    let followers = followers::parse(LIST, ',').unwrap();
+    let names: Vec<(&str, &str)> = followers
+        .iter()
+        .map(|follower| (follower.first_name, follower.last_name))
+        .collect();
+    assert_eq!(
+        names,
+        [("script", "saga"), ("ferris", "crab"), ("grace", "hopper")]
+    );
+
+    let text = LIST.as_bytes().as_ptr_range();
+    for follower in &followers {
+        assert!(text.contains(&follower.first_name.as_ptr()));
+        assert!(text.contains(&follower.last_name.as_ptr()));
+    }
+}
+
+#[test]
+fn tutorial_followers_copy_only_the_last_name() {
+    let follower = ScriptSagaFollower::from(followers::parse(LIST, ',').unwrap()[0]);
+    assert_eq!(
+        (follower.first_name(), follower.last_name()),
+        ("script", "saga")
+    );
+
+    let text = LIST.as_bytes().as_ptr_range();
+    assert!(text.contains(&follower.first_name().as_ptr()));
+    assert!(!text.contains(&follower.last_name().as_ptr()));
+}
+
+#[test]
+fn reading_followers_does_not_allocate() {
+    let (count, stats) = alloc_counter::measure(|| {
+        Followers::new(LIST)
+            .map(|follower| follower.unwrap().first_name.len())
+            .sum::<usize>()
+    });
+    assert_eq!(count, "scriptferrisgrace".len());
+    assert!(stats.is_empty(), "{}", stats);
+
+    // The exact count for the Vec depends on how it grows, the names are one allocation each
+    let (tutorial, stats) = alloc_counter::measure(|| {
+        Followers::new(LIST)
+            .map(|follower| ScriptSagaFollower::from(follower.unwrap()))
+            .collect::<Vec<_>>()
+    });
+    assert!(stats.allocations > tutorial.len(), "{}", stats);
+    assert!(stats.allocations < 2 * tutorial.len(), "{}", stats);
+
+    let (owned, stats) = alloc_counter::measure(|| followers::parse_owned(LIST, ',').unwrap());
+    assert_eq!(owned.len(), 3);
+    assert!(stats.allocations > 2 * owned.len(), "{}", stats);
+}
+
+#[test]
+fn owned_and_borrowed_parsers_agree() {
+    let borrowed: Vec<OwnedFollower> = followers::parse(LIST, ',')
+        .unwrap()
+        .into_iter()
+        .map(OwnedFollower::from)
+        .collect();
+    assert_eq!(borrowed, followers::parse_owned(LIST, ',').unwrap());
+}
+
+#[test]
+fn errors_point_at_line_and_column() {
+    let cases = [
+        ("ferris", 1, 7, "expected `,` and a last name"),
+        ("a, b, c", 1, 5, "expected 2 fields, found another `,`"),
+        ("ok, fine\n, crab", 2, 1, "the first name is empty"),
+        ("script,   ", 1, 11, "the last name is empty"),
+        ("écrit, saga\nñ,", 2, 3, "the last name is empty"),
+    ];
+    for (text, line, column, message) in cases {
+        let error = followers::parse(text, ',').unwrap_err();
+        assert_eq!(
+            location(error),
+            (line, Some(column), message.to_string()),
+            "{:?}",
+            text
+        );
+    }
+
+    let error = followers::parse("ferris", ',').unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 1, column 7: expected `,` and a last name"
+    );
+}
+
+#[test]
+fn iterator_carries_on_after_a_bad_line() {
+    let results: Vec<_> = Followers::new("a, b\nbad\nc, d").collect();
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok() && results[1].is_err() && results[2].is_ok());
+    assert_eq!(results[2].as_ref().unwrap().first_name, "c");
+}
+
+#[test]
+fn other_delimiters() {
+    let text = "script\tsaga\nferris\tcrab, the";
+    let followers: Vec<_> = Followers::new(text)
+        .delimiter('\t')
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(followers[1].last_name, "crab, the");
+    assert_eq!(followers, followers::parse(text, '\t').unwrap());
+    assert_eq!(
+        followers::parse_owned(text, '\t').unwrap()[1].last_name,
+        "crab, the"
+    );
+}
@@ -0,0 +1,152 @@
+// The HTML site and the tokenizer that highlights its code
+
+use practice_one::{escape_html, highlight_html, tokenize, Registry, TokenKind};
+use std::{
+    env, fs,
+    path::PathBuf,
+    process::{self, Command},
+};
+
+fn kinds(code: &str) -> Vec<(TokenKind, &str)> {
+    tokenize(code)
+        .into_iter()
+        .filter(|token| token.kind != TokenKind::Whitespace)
+        .map(|token| (token.kind, token.text))
+        .collect()
+}
+
+#[test]
+fn tokens_cover_the_whole_input() {
+    let code = "pub fn f<'a>(x: &'a str) -> String { println!(\"{}\", x); 0..10 } // done";
+    let joined: String = tokenize(code).iter().map(|token| token.text).collect();
+    assert_eq!(joined, code);
+}
+
+#[test]
+fn tokens_get_the_right_kind() {
+    assert_eq!(
+        kinds("let x: Vec<u8> = vec![1.5, 0x_ff]; 'a: loop {}"),
+        [
+            (TokenKind::Keyword, "let"),
+            (TokenKind::Identifier, "x"),
+            (TokenKind::Punctuation, ":"),
+            (TokenKind::Type, "Vec"),
+            (TokenKind::Punctuation, "<"),
+            (TokenKind::Identifier, "u8"),
+            (TokenKind::Punctuation, ">"),
+            (TokenKind::Punctuation, "="),
+            (TokenKind::Macro, "vec!"),
+            (TokenKind::Punctuation, "["),
+            (TokenKind::Number, "1.5"),
+            (TokenKind::Punctuation, ","),
+            (TokenKind::Number, "0x_ff"),
+            (TokenKind::Punctuation, "]"),
+            (TokenKind::Punctuation, ";"),
+            (TokenKind::Lifetime, "'a"),
+            (TokenKind::Punctuation, ":"),
+            (TokenKind::Keyword, "loop"),
+            (TokenKind::Punctuation, "{"),
+            (TokenKind::Punctuation, "}"),
+        ]
+    );
+    assert_eq!(
+        kinds("0..10 /* a /* nested */ comment */ r#\"raw \"str\"\"# b\"\\\"\""),
+        [
+            (TokenKind::Number, "0"),
+            (TokenKind::Punctuation, "."),
+            (TokenKind::Punctuation, "."),
+            (TokenKind::Number, "10"),
+            (TokenKind::Comment, "/* a /* nested */ comment */"),
+            (TokenKind::String, "r#\"raw \"str\"\"#"),
+            (TokenKind::String, "b\"\\\"\""),
+        ]
+    );
+}
+
+#[test]
+fn char_literals_with_escapes() {
+    assert_eq!(
+        kinds(r"'x' '\n' '\'' '\\' '\u{1F980}' 'static"),
+        [
+            (TokenKind::Char, "'x'"),
+            (TokenKind::Char, r"'\n'"),
+            (TokenKind::Char, r"'\''"),
+            (TokenKind::Char, r"'\\'"),
+            (TokenKind::Char, r"'\u{1F980}'"),
+            (TokenKind::Lifetime, "'static"),
+        ]
+    );
+}
+
+#[test]
+fn html_is_escaped() {
+    assert_eq!(
+        escape_html("<a href=\"x\">&</a>"),
+        "&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;"
+    );
+    assert_eq!(escape_html("plain 'text'"), "plain 'text'");
+    assert_eq!(
+        highlight_html("a < b // <b>"),
+        "a &lt; b <span class=\"comment\">// &lt;b&gt;</span>"
+    );
+    assert_eq!(
+        highlight_html("\"<&>\""),
+        "<span class=\"string\">&quot;&lt;&amp;&gt;&quot;</span>"
+    );
+}
+
+// The pages are only reachable through the html command, like a learner would build them
+fn generate_site(name: &str) -> PathBuf {
+    let out_dir = env::temp_dir().join(format!("practice_one_html_{}_{}", process::id(), name));
+    let status = Command::new(env!("CARGO_BIN_EXE_practice_one"))
+        .arg("html")
+        .arg(&out_dir)
+        .env("PRACTICE_ONE_PROGRESS", out_dir.join("progress"))
+        .output()
+        .expect("failed to run practice_one")
+        .status;
+    assert!(status.success());
+    out_dir
+}
+
+#[test]
+fn pages_link_to_their_neighbours() {
+    let registry = Registry::with_all_lessons();
+    let tutorials = registry.tutorials();
+    let out_dir = generate_site("pages");
+    let page =
+        |tutorial: u8| fs::read_to_string(out_dir.join(format!("tut{}.html", tutorial))).unwrap();
+    let first = page(tutorials[0]);
+    let second = page(tutorials[1]);
+
+    assert!(first.starts_with("<!DOCTYPE html>"));
+    assert!(!first.contains("&larr;"));
+    assert!(first.contains(&format!("href=\"tut{}.html\">Tutorial", tutorials[1])));
+    assert!(second.contains(&format!("href=\"tut{}.html\">&larr;", tutorials[0])));
+    assert!(second.contains("<span class=\"keyword\">fn</span>"));
+    assert!(!second.contains("fn</span> register("));
+
+    let index = fs::read_to_string(out_dir.join("index.html")).unwrap();
+    for tutorial in tutorials {
+        assert!(index.contains(&format!("<a href=\"tut{}.html\">", tutorial)));
+    }
+    fs::remove_dir_all(&out_dir).unwrap();
+}
+
+#[test]
+fn generate_writes_a_self_contained_site() {
+    let registry = Registry::with_all_lessons();
+    let out_dir = generate_site("generate");
+
+    let pages = fs::read_dir(&out_dir)
+        .unwrap()
+        .filter(|entry| entry.as_ref().unwrap().path().extension() == Some("html".as_ref()))
+        .count();
+    assert_eq!(pages, registry.tutorials().len() + 1);
+    assert!(out_dir.join("index.html").is_file());
+    let page = fs::read_to_string(out_dir.join("tut10.html")).unwrap();
+    assert!(page.contains("<img src=\"images/hash%20map.png\" alt=\"hash map.png\">"));
+    assert!(out_dir.join("images/hash map.png").is_file());
+
+    fs::remove_dir_all(&out_dir).unwrap();
+}
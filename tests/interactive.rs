@@ -0,0 +1,179 @@
+// The lesson browser, driven by a fixed input instead of a terminal
+
+use practice_one::{
+    browse, explanation, function_line,
+    progress::{Kind, Progress},
+    tutorial_source, LessonInfo, Registry,
+};
+use std::{
+    env, fs,
+    io::{self, Write},
+    path::PathBuf,
+    process,
+};
+
+fn first(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "output of the first lesson")
+}
+
+fn second(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "output of the second lesson")
+}
+
+fn panicking(_: &mut dyn Write) -> io::Result<()> {
+    panic!("boom")
+}
+
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+    for (id, entry) in [
+        ("first", first as fn(&mut dyn Write) -> io::Result<()>),
+        ("second", second),
+        ("panicking", panicking),
+    ] {
+        registry.register(LessonInfo {
+            id,
+            title: "A test lesson",
+            tutorial: 2,
+            tags: &[],
+            entry_name: id,
+            entry,
+            diagrams: &[],
+        });
+    }
+    registry
+}
+
+// A progress file of its own for every test, removed again at the end
+struct TempProgress(PathBuf);
+
+impl TempProgress {
+    fn new(name: &str) -> Self {
+        let path = env::temp_dir().join(format!(
+            "practice_one_interactive_{}_{}",
+            process::id(),
+            name
+        ));
+        fs::remove_file(&path).ok();
+        TempProgress(path)
+    }
+
+    fn open(&self) -> Progress {
+        Progress::open(self.0.clone(), "tester".to_string()).unwrap()
+    }
+}
+
+impl Drop for TempProgress {
+    fn drop(&mut self) {
+        fs::remove_file(&self.0).ok();
+    }
+}
+
+fn browse_with(progress: &mut Progress, input: &str) -> String {
+    let mut input = input.as_bytes();
+    let mut out = Vec::new();
+    browse(&registry(), progress, &mut input, &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn picks_lessons_by_number_and_by_name() {
+    let file = TempProgress::new("pick");
+    let mut progress = file.open();
+    let out = browse_with(&mut progress, "1\nm\nsecond\nq\n");
+
+    assert!(out.contains("tut2\n   1. first"));
+    assert!(out.contains("=== tut2::first - A test lesson ==="));
+    assert!(out.contains("output of the first lesson"));
+    assert!(out.contains("output of the second lesson"));
+    assert_eq!(progress.records().count(), 2);
+}
+
+#[test]
+fn next_and_previous_stop_at_the_ends() {
+    let file = TempProgress::new("ends");
+    let mut progress = file.open();
+    let out = browse_with(&mut progress, "1\np\nn\n\nn\nq\n");
+
+    assert!(out.contains("that was the first lesson"));
+    assert!(out.contains("output of the second lesson"));
+    assert!(out.contains("that was the last lesson"));
+}
+
+#[test]
+fn unknown_answers_ask_again() {
+    let file = TempProgress::new("unknown");
+    let mut progress = file.open();
+    let out = browse_with(&mut progress, "nope\n9\nFIRST\nx\nq\n");
+
+    assert!(out.contains("no lesson `nope`, try again"));
+    assert!(out.contains("no lesson `9`, try again"));
+    assert!(out.contains("output of the first lesson"));
+    assert!(out.contains("unknown choice `x`"));
+}
+
+#[test]
+fn end_of_input_quits() {
+    let file = TempProgress::new("eof");
+    let mut progress = file.open();
+    let out = browse_with(&mut progress, "2\n");
+
+    assert!(out.contains("output of the second lesson"));
+    assert!(out.ends_with("> \n"));
+}
+
+#[test]
+fn a_panicking_lesson_does_not_stop_the_browser() {
+    let file = TempProgress::new("panic");
+    let mut progress = file.open();
+    let out = browse_with(&mut progress, "panicking\np\nq\n");
+
+    // The failure is reported after the lesson output, not mixed into it
+    let (_, after_output) = out.split_once("--- end of output ---\n").unwrap();
+    assert!(after_output.starts_with("error: tut2::panicking failed\n\ncaused by:\n"));
+    assert!(after_output.contains(": boom\n"));
+    assert!(out.contains("output of the second lesson"));
+
+    // Only the lesson that worked counts as done
+    let ids: Vec<String> = file
+        .open()
+        .records()
+        .map(|record| record.id.clone())
+        .collect();
+    assert_eq!(ids, ["tut2::second"]);
+}
+
+#[test]
+fn offers_to_resume_after_the_last_lesson() {
+    let file = TempProgress::new("resume");
+    let mut progress = file.open();
+    progress.record(Kind::Lesson, "tut2::first", None).unwrap();
+
+    let mut progress = file.open();
+    let out = browse_with(&mut progress, "\nq\n");
+    assert!(out.contains("Welcome back tester, last time you did tut2::first"));
+    assert!(out.contains("resume with tut2::second? [Y/n] > "));
+    assert!(out.contains("output of the second lesson"));
+
+    let out = browse_with(&mut progress, "n\nq\n");
+    assert!(out.contains("pick a lesson by number or name"));
+}
+
+#[test]
+fn explanations_come_from_the_tutorial_comments() {
+    let tut10 = tutorial_source(10).unwrap();
+    let line = function_line(tut10, "understanding_hash_map").unwrap();
+    assert!(tut10
+        .lines()
+        .nth(line)
+        .unwrap()
+        .contains("fn understanding_hash_map("));
+
+    let text = explanation(tut10, "understanding_hash_map").unwrap();
+    assert!(text.starts_with("A hash map is used as key-value storage."));
+    assert!(text.contains("You need to specify the type of key-value pairs"));
+    assert!(!text.contains("//"));
+
+    assert_eq!(tutorial_source(1), None);
+    assert_eq!(explanation(tut10, "no_such_function"), None);
+}
@@ -0,0 +1,127 @@
+use practice_one::{
+    alloc_counter::{self, CountingAllocator},
+    interner::Interner,
+    lessons::tut5::{InternedFollower, ScriptSagaFollower},
+};
+use std::mem;
+
+// To check that looking up a known name doesn't allocate
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn same_string_gives_the_same_symbol() {
+    let mut interner = Interner::new();
+    let one = interner.intern("script_saga_follower_one");
+    let two = interner.intern("script_saga_follower_two");
+    let again = interner.intern(&String::from("script_saga_follower_one"));
+
+    assert_eq!(one, again);
+    assert_ne!(one, two);
+    assert_eq!((one.index(), two.index()), (0, 1));
+    assert_eq!(interner.len(), 2);
+    assert_eq!(interner.resolve(one), "script_saga_follower_one");
+    assert_eq!(interner.resolve(two), "script_saga_follower_two");
+}
+
+#[test]
+fn get_finds_only_interned_strings() {
+    let mut interner = Interner::new();
+    assert!(interner.is_empty());
+    assert_eq!(interner.get("ferris"), None);
+
+    let ferris = interner.intern("ferris");
+    assert_eq!(interner.get("ferris"), Some(ferris));
+    assert_eq!(interner.get("crab"), None);
+    assert_eq!(interner.len(), 1);
+}
+
+#[test]
+fn lookups_of_known_strings_do_not_allocate() {
+    let mut interner = Interner::new();
+    let names = ["script", "saga", "ferris"];
+    for name in names {
+        interner.intern(name);
+    }
+
+    let (symbols, stats) = alloc_counter::measure(|| {
+        let mut symbols = [None; 3];
+        for (slot, name) in symbols.iter_mut().zip(names) {
+            *slot = interner.get(name);
+        }
+        symbols
+    });
+    assert!(stats.is_empty(), "get allocated: {}", stats);
+    assert!(symbols.iter().all(Option::is_some));
+
+    let (_, stats) = alloc_counter::measure(|| {
+        for _ in 0..100 {
+            for name in names {
+                interner.intern(name);
+            }
+        }
+    });
+    assert!(
+        stats.is_empty(),
+        "interning known names allocated: {}",
+        stats
+    );
+    assert_eq!(interner.len(), 3);
+}
+
+#[test]
+fn iter_lists_strings_in_interning_order() {
+    let mut interner = Interner::with_capacity(4);
+    for name in ["b", "a", "b", "c"] {
+        interner.intern(name);
+    }
+    let listed: Vec<(usize, &str)> = interner
+        .iter()
+        .map(|(symbol, name)| (symbol.index(), name))
+        .collect();
+    assert_eq!(listed, [(0, "b"), (1, "a"), (2, "c")]);
+    assert_eq!(interner.bytes(), 3);
+}
+
+#[test]
+fn followers_with_duplicate_names_share_storage() {
+    let mut interner = Interner::new();
+    let followers: Vec<InternedFollower> = (0..5000u64)
+        .map(|index| {
+            let first_name = format!("script_saga_follower_{}", index % 10);
+            InternedFollower::new(&mut interner, &first_name, "saga", index, 91)
+        })
+        .collect();
+
+    assert_eq!(interner.len(), 11);
+    assert_eq!(
+        followers[0].first_name_symbol(),
+        followers[10].first_name_symbol()
+    );
+    assert_eq!(followers[7].first_name(&interner), "script_saga_follower_7");
+    assert_eq!(followers[7].last_name(&interner), "saga");
+    assert_eq!(followers[7].mobile_number(), 7);
+    assert!(mem::size_of::<InternedFollower>() < mem::size_of::<ScriptSagaFollower>());
+}
+
+#[test]
+fn followers_convert_both_ways() {
+    let mut interner = Interner::new();
+    let follower = ScriptSagaFollower::new("script".to_string(), "saga".to_string(), 123456789, 91);
+    let interned = follower.intern(&mut interner);
+
+    assert_eq!(interned.first_name(&interner), "script");
+    assert_eq!(interned.country_code(), 91);
+    assert_eq!(interned.to_follower(&interner), follower);
+    assert_eq!(follower.intern(&mut interner), interned);
+    assert_eq!(interner.len(), 2);
+}
+
+#[test]
+#[should_panic]
+fn symbols_of_a_bigger_interner_panic() {
+    let mut bigger = Interner::new();
+    bigger.intern("script");
+    let saga = bigger.intern("saga");
+    Interner::new().resolve(saga);
+}
@@ -0,0 +1,190 @@
+use practice_one::layout::{self, Field, Shape, TypeLayout, Variant};
+use std::mem;
+
+fn find(name: &str) -> TypeLayout {
+    layout::all()
+        .into_iter()
+        .find(|layout| layout.name == name)
+        .unwrap_or_else(|| panic!("no layout for {}", name))
+}
+
+#[test]
+fn every_lesson_type_is_listed() {
+    let names: Vec<&str> = layout::all().iter().map(|layout| layout.name).collect();
+    for name in [
+        "tut4::ScriptSagaFollower",
+        "tut5::ScriptSagaFollower",
+        "tut5::CartesianCoordinate",
+        "tut5::ScriptSagaEnum",
+        "tut7::Point",
+        "tut7::Coordinate",
+    ] {
+        assert!(names.contains(&name), "{} missing from {:?}", name, names);
+    }
+}
+
+#[test]
+fn fields_fit_inside_the_struct_without_overlapping() {
+    for layout in layout::all() {
+        assert_eq!(layout.size % layout.align, 0, "{}", layout.name);
+        if let Shape::Struct(fields) = &layout.shape {
+            let mut end = 0;
+            for field in fields {
+                assert!(
+                    field.offset >= end,
+                    "{}.{} overlaps",
+                    layout.name,
+                    field.name
+                );
+                end = field.offset + field.size;
+            }
+            assert!(
+                end <= layout.size,
+                "{} fields go past its size",
+                layout.name
+            );
+        }
+    }
+}
+
+#[test]
+fn every_table_adds_up() {
+    for layout in layout::all() {
+        match layout.shape {
+            Shape::Struct(_) => assert!(layout.padding().is_some(), "{}", layout.name),
+            Shape::Enum { .. } => assert!(layout.tag_and_padding().is_some(), "{}", layout.name),
+        }
+        assert!(!layout.to_string().contains("the table is larger"));
+    }
+}
+
+#[test]
+fn a_table_larger_than_its_type_is_reported() {
+    let wrong = TypeLayout {
+        name: "tut7::Wrong",
+        size: 4,
+        align: 4,
+        shape: Shape::Struct(vec![Field {
+            name: "x",
+            type_name: "i64",
+            offset: 0,
+            size: 8,
+        }]),
+    };
+    assert_eq!(wrong.padding(), None);
+    assert!(wrong
+        .to_string()
+        .contains("(the table is larger than the type, its field types are wrong)"));
+
+    let wrong = TypeLayout {
+        shape: Shape::Enum {
+            variants: vec![Variant {
+                name: "Big",
+                payload: "u64",
+                size: 8,
+            }],
+            option_size: 8,
+        },
+        ..wrong
+    };
+    assert_eq!(wrong.tag_and_padding(), None);
+    assert!(wrong
+        .to_string()
+        .contains("the table is larger than the type"));
+}
+
+#[test]
+fn sizes_match_std_mem() {
+    let coordinate = find("tut7::Coordinate");
+    assert_eq!(coordinate.size, 3 * mem::size_of::<i32>());
+    assert_eq!(coordinate.align, mem::align_of::<i32>());
+    assert_eq!(coordinate.padding(), Some(0));
+
+    let follower = find("tut5::ScriptSagaFollower");
+    assert_eq!(
+        follower.size,
+        mem::size_of::<practice_one::lessons::tut5::ScriptSagaFollower>()
+    );
+    assert!(follower.padding().unwrap() > 0);
+
+    assert_eq!(find("tut9::Human").size, 0);
+}
+
+#[test]
+fn heap_is_reported_for_owned_strings_only() {
+    assert!(layout::owns_heap("String"));
+    assert!(layout::owns_heap("Vec<i32>"));
+    assert!(!layout::owns_heap("&'static str"));
+    assert!(!layout::owns_heap("(i32, i32, i32)"));
+
+    assert!(find("tut4::ScriptSagaFollower").owns_heap());
+    assert!(find("tut5::ScriptSagaFollower").owns_heap());
+    assert!(find("tut5::ScriptSagaEnum").owns_heap());
+    assert!(!find("tut7::Point").owns_heap());
+}
+
+#[test]
+fn enums_report_their_tag_and_padding() {
+    let recovery = find("tut11::Recovery");
+    assert_eq!(recovery.tag_and_padding(), Some(1));
+    assert_eq!(recovery.padding(), None);
+
+    let saga = find("tut5::ScriptSagaEnum");
+    let Shape::Enum {
+        variants,
+        option_size,
+    } = &saga.shape
+    else {
+        panic!("ScriptSagaEnum is an enum");
+    };
+    assert_eq!(variants.len(), 4);
+    let largest = variants.iter().map(|variant| variant.size).max().unwrap();
+    assert_eq!(saga.tag_and_padding(), Some(saga.size - largest));
+    assert!(*option_size >= saga.size);
+}
+
+#[test]
+fn the_tag_is_shown_with_the_padding_after_it() {
+    #[allow(dead_code)]
+    enum Tagged {
+        Small(u8),
+        Big(u64),
+    }
+    let tagged = TypeLayout::of_enum::<Tagged>(
+        "tests::Tagged",
+        vec![
+            Variant {
+                name: "Small",
+                payload: "u8",
+                size: mem::size_of::<u8>(),
+            },
+            Variant {
+                name: "Big",
+                payload: "u64",
+                size: mem::size_of::<u64>(),
+            },
+        ],
+    );
+    // A 1 byte tag, and the enum rounded up to the alignment of the u64
+    assert_eq!(
+        tagged.tag_and_padding(),
+        Some(mem::size_of::<Tagged>() - mem::size_of::<u64>())
+    );
+    assert_eq!(tagged.tag_and_padding(), Some(tagged.align));
+    assert!(tagged.to_string().contains(&format!(
+        "tag + padding: {} bytes next to the largest payload",
+        tagged.align
+    )));
+    assert!(!tagged.to_string().contains("discriminant"));
+}
+
+#[test]
+fn table_lists_every_type() {
+    let layouts = layout::all();
+    let table = layout::summary(&layouts);
+    assert!(table.starts_with("type"));
+    assert_eq!(table.lines().count(), layouts.len() + 1);
+    assert!(find("tut7::Coordinate")
+        .to_string()
+        .contains("    z               i32                    8     4  no"));
+}
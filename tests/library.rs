@@ -0,0 +1,154 @@
+// The library API used from outside the crate
+//
+// Types from the tutorials and the registry should be usable by other crates without going through
+// the practice_one binary.
+
+use practice_one::{
+    error,
+    lessons::{
+        tut2, tut4,
+        tut5::{self, CartesianCoordinate},
+        tut7::{self, Coordinate},
+        tut9::{Human, Pilot, Wizard},
+    },
+    quiz, Error, Registry,
+};
+use std::{error::Error as _, io};
+
+#[test]
+fn followers_are_built_through_constructors() {
+    let first_name = String::from("script");
+    let follower = tut4::ScriptSagaFollower::new(&first_name, "saga".to_string());
+    assert_eq!(follower.first_name(), "script");
+    assert_eq!(follower.last_name(), "saga");
+
+    let follower = tut5::ScriptSagaFollower::new("script".into(), "saga".into(), 123456789, 91);
+    assert_eq!(follower.mobile_number(), 123456789);
+    assert_eq!(follower.country_code(), 91);
+}
+
+#[test]
+fn coordinates_have_public_fields() {
+    let CartesianCoordinate(x, y, z) = CartesianCoordinate(1, 2, 3);
+    assert_eq!((x, y, z), (1, 2, 3));
+
+    let coordinate = Coordinate { x: 1, y: 2, z: 3 };
+    assert_eq!(coordinate.x + coordinate.y + coordinate.z, 6);
+}
+
+#[test]
+fn trait_methods_write_to_any_writer() {
+    let mut out = Vec::new();
+    Pilot::fly(&Human, &mut out).unwrap();
+    Wizard::fly(&Human, &mut out).unwrap();
+    Human.fly(&mut out).unwrap();
+
+    let out = String::from_utf8(out).unwrap();
+    assert_eq!(out.lines().count(), 3);
+    assert!(out.contains("Human flying"));
+}
+
+#[test]
+fn lessons_run_through_the_registry() {
+    let registry = Registry::with_all_lessons();
+    let lesson = registry
+        .find(10, "strings")
+        .expect("tut10::strings is registered");
+    assert_eq!(lesson.qualified_id(), "tut10::strings");
+
+    let mut out = Vec::new();
+    lesson.run(&mut out).unwrap();
+    assert!(!out.is_empty());
+}
+
+#[test]
+fn errors_can_be_matched_by_kind() {
+    assert!(matches!(tut2::function_return_a_result(), Ok(1)));
+
+    let bank = "== question\nkind: choice\nlesson: result\nprompt: ?\nanswer: 3\n";
+    match quiz::parse(bank) {
+        Err(Error::Parse { line, .. }) => assert_eq!(line, 1),
+        other => panic!("expected a parse error, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn context_keeps_the_cause_as_source() {
+    let error = Error::from(io::Error::new(io::ErrorKind::NotFound, "missing file"))
+        .context("could not load the quiz");
+
+    assert_eq!(error.to_string(), "could not load the quiz");
+    assert_eq!(error.source().unwrap().to_string(), "missing file");
+    assert!(
+        matches!(error.root(), Error::Io(io_error) if io_error.kind() == io::ErrorKind::NotFound)
+    );
+}
+
+#[test]
+fn failing_condition_is_an_error_not_a_panic() {
+    assert!(matches!(
+        tut7::something_that_returns_result_type(false),
+        Err(Error::Validation(_))
+    ));
+    assert!(matches!(
+        tut7::something_that_returns_result_type(true),
+        Ok(1)
+    ));
+}
+
+#[test]
+fn panics_are_caught_as_errors() {
+    let caught = error::catch_panic(|| -> i32 { panic!("lesson blew up") });
+    match caught {
+        Err(Error::Panic { message, location }) => {
+            assert_eq!(message, "lesson blew up");
+            assert!(location.unwrap().contains("library.rs"));
+        }
+        other => panic!("expected a caught panic, got {:?}", other),
+    }
+
+    assert_eq!(error::catch_panic(|| 5).unwrap(), 5);
+}
+
+#[test]
+fn panics_on_other_threads_are_caught_as_their_own() {
+    let threads: Vec<_> = (0..8)
+        .map(|number| {
+            std::thread::spawn(move || {
+                (0..50)
+                    .map(
+                        |_| match error::catch_panic(|| -> () { panic!("thread {}", number) }) {
+                            Err(Error::Panic { message, .. }) => message,
+                            other => panic!("expected a caught panic, got {:?}", other),
+                        },
+                    )
+                    .all(|message| message == format!("thread {}", number))
+            })
+        })
+        .collect();
+    for thread in threads {
+        assert!(thread.join().unwrap());
+    }
+}
+
+#[test]
+fn nested_catches_keep_capturing() {
+    let outer = error::catch_panic(|| {
+        let inner = error::catch_panic(|| -> () { panic!("inner") });
+        assert!(matches!(inner, Err(Error::Panic { ref message, .. }) if message == "inner"));
+        panic!("outer")
+    });
+    assert!(matches!(outer, Err(Error::Panic { ref message, .. }) if message == "outer"));
+}
+
+#[test]
+fn report_numbers_the_causes() {
+    let error = Error::Validation("condition is false".to_string())
+        .context("tut7::pattern_matching failed")
+        .context("1 of 2 lessons failed");
+
+    assert_eq!(
+        error::report(&error),
+        "error: 1 of 2 lessons failed\n\ncaused by:\n    1: tut7::pattern_matching failed\n    2: condition is false\n"
+    );
+}
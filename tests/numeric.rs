@@ -0,0 +1,226 @@
+use practice_one::{
+    numeric::{self, Number, NumericType, Op, Outcome},
+    Error,
+};
+use std::{
+    env, fs,
+    process::{self, Command},
+};
+
+fn outcome(ty: NumericType, a: &str, op: Op, b: &str) -> Outcome {
+    let a = numeric::parse_literal(a).unwrap();
+    let b = numeric::parse_literal(b).unwrap();
+    numeric::arithmetic(ty, &a, op, &b).unwrap()
+}
+
+#[test]
+fn ranges_come_from_the_types() {
+    assert_eq!(NumericType::U8.size(), 1);
+    assert_eq!(NumericType::I128.size(), 16);
+    assert_eq!(NumericType::I8.min(), "-128");
+    assert_eq!(NumericType::U16.max(), "65535");
+    assert_eq!(NumericType::F32.max(), "3.4028235e38");
+    assert_eq!(NumericType::from_name("usize"), Some(NumericType::Usize));
+    assert_eq!(NumericType::from_name("u7"), None);
+    assert!(NumericType::I64.is_signed() && !NumericType::U64.is_signed());
+    assert_eq!(NumericType::ALL.len(), 14);
+}
+
+#[test]
+fn literals_in_every_base_with_underscores_and_suffixes() {
+    let cases = [
+        ("1_000_000", 1_000_000u128, 10, None),
+        ("0xff", 255, 16, None),
+        ("0b1010_1010u8", 170, 2, Some(NumericType::U8)),
+        ("0o777", 511, 8, None),
+        ("32u8", 32, 10, Some(NumericType::U8)),
+        ("0x1f32", 0x1f32, 16, None),
+        ("7_i128", 7, 10, Some(NumericType::I128)),
+    ];
+    for (text, magnitude, radix, suffix) in cases {
+        let literal = numeric::parse_literal(text).unwrap();
+        assert_eq!(
+            literal.value,
+            Number::Integer {
+                negative: false,
+                magnitude
+            },
+            "{}",
+            text
+        );
+        assert_eq!((literal.radix, literal.suffix), (radix, suffix), "{}", text);
+    }
+
+    let float = numeric::parse_literal("2.5e3f32").unwrap();
+    assert_eq!(float.value, Number::Float(2500.0));
+    assert_eq!(float.default_type(), NumericType::F32);
+    assert_eq!(
+        numeric::parse_literal("1.").unwrap().default_type(),
+        NumericType::F64
+    );
+}
+
+#[test]
+fn literals_know_which_types_they_fit() {
+    let literal = numeric::parse_literal("-129").unwrap();
+    assert!(!literal.fits(NumericType::I8));
+    assert!(literal.fits(NumericType::I16));
+    assert!(!literal.fits(NumericType::U128));
+    assert_eq!(literal.default_type(), NumericType::I32);
+
+    let min = numeric::parse_literal("-170141183460469231731687303715884105728").unwrap();
+    assert!(min.fits(NumericType::I128));
+    assert_eq!(
+        numeric::parse_literal("300").unwrap().types().first(),
+        Some(&NumericType::I16)
+    );
+}
+
+#[test]
+fn invalid_literals_are_explained() {
+    for (text, expected) in [
+        ("256u8", "out of range for u8, which holds 0 to 255"),
+        ("-1u32", "out of range for u32"),
+        ("1.5u8", "can't have the integer suffix u8"),
+        ("0b102", "isn't valid in base 2"),
+        ("_1", "starts with an underscore"),
+        ("0x", "has no digits"),
+        ("1e400f32", "out of range for f32"),
+        (
+            "340282366920938463463374607431768211456",
+            "too big even for u128",
+        ),
+    ] {
+        match numeric::parse_literal(text) {
+            Err(Error::Validation(message)) => {
+                assert!(message.contains(expected), "{}: {}", text, message)
+            }
+            other => panic!("{} parsed as {:?}", text, other),
+        }
+    }
+}
+
+#[test]
+fn overflow_modes() {
+    assert_eq!(
+        outcome(NumericType::U8, "250", Op::Add, "10"),
+        Outcome::Integer {
+            checked: None,
+            wrapping: "4".to_string(),
+            saturating: "255".to_string(),
+            overflowing: "(4, true)".to_string(),
+        }
+    );
+    assert_eq!(
+        outcome(NumericType::I8, "-128", Op::Div, "-1"),
+        Outcome::Integer {
+            checked: None,
+            wrapping: "-128".to_string(),
+            saturating: "127".to_string(),
+            overflowing: "(-128, true)".to_string(),
+        }
+    );
+    match outcome(NumericType::U32, "2", Op::Mul, "3") {
+        Outcome::Integer { checked, .. } => assert_eq!(checked.as_deref(), Some("6")),
+        other => panic!("{:?}", other),
+    }
+    match outcome(NumericType::U8, "1", Op::Div, "0") {
+        Outcome::Integer {
+            checked, wrapping, ..
+        } => {
+            assert_eq!(checked, None);
+            assert!(wrapping.starts_with("panics"));
+        }
+        other => panic!("{:?}", other),
+    }
+    assert_eq!(
+        outcome(NumericType::F64, "1.0", Op::Div, "0.0"),
+        Outcome::Float("inf".to_string())
+    );
+}
+
+#[test]
+fn integers_and_floats_dont_mix() {
+    // let x: f64 = 255; doesn't compile, an integer literal is never a float
+    let literal = numeric::parse_literal("0xff").unwrap();
+    assert!(!literal.fits(NumericType::F32));
+    assert!(!literal.fits(NumericType::F64));
+    assert!(!literal.types().iter().any(|ty| ty.is_float()));
+
+    for (line, message) in [
+        ("1.0 / 0", "mismatched types {float} and {integer}"),
+        ("1 + 2.5", "mismatched types {integer} and {float}"),
+        ("1 * 2.5f32", "mismatched types {integer} and f32"),
+        ("3u8 - 1.5", "mismatched types u8 and {float}"),
+    ] {
+        match numeric::evaluate(line) {
+            Err(Error::Validation(error)) => assert!(error.starts_with(message), "{}", error),
+            other => panic!("{}: expected a type error, got {:?}", line, other),
+        }
+    }
+    assert!(numeric::evaluate("1.0 / 0.0").unwrap().contains("inf"));
+
+    let one = numeric::parse_literal("1").unwrap();
+    assert!(numeric::arithmetic(NumericType::F64, &one, Op::Add, &one).is_err());
+}
+
+#[test]
+fn the_explorer_command_does_not_hide_the_numbers_lesson() {
+    let progress = env::temp_dir().join(format!("practice_one_numeric_{}", process::id()));
+    let run = |args: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_practice_one"))
+            .args(args)
+            .env("PRACTICE_ONE_PROGRESS", &progress)
+            .output()
+            .expect("failed to run practice_one");
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    // `practice_one numbers` is the tut3 lesson with that id
+    assert!(run(&["numbers"]).contains("1_000_000 is 1000000"));
+    assert_eq!(
+        run(&["explore-numbers", "250u8", "+", "10"]),
+        numeric::evaluate("250u8 + 10").unwrap()
+    );
+    fs::remove_file(&progress).ok();
+}
+
+#[test]
+fn float_edge_cases_hold() {
+    let cases = numeric::float_edge_cases();
+    let result = |expression: &str| {
+        cases
+            .iter()
+            .find(|(name, _)| *name == expression)
+            .map(|(_, result)| result.as_str())
+            .unwrap()
+    };
+    assert_eq!(result("0.0 / 0.0"), "NaN");
+    assert_eq!(result("NAN == NAN"), "false");
+    assert_eq!(result("-1.0 / 0.0"), "-inf");
+    assert_eq!(result("0.1 + 0.2 == 0.3"), "false");
+    assert_eq!(result("300.0 as u8"), "255");
+}
+
+#[test]
+fn evaluate_and_explore() {
+    assert!(numeric::evaluate("u8").unwrap().contains("0 to 255"));
+    assert!(numeric::evaluate("0xff")
+        .unwrap()
+        .contains("0xff is 255, an i32"));
+    assert!(numeric::evaluate("250u8 + 10")
+        .unwrap()
+        .contains("saturating   255"));
+    assert!(numeric::evaluate("1u8 + 1i8").is_err());
+    assert!(numeric::evaluate("1 % 2").is_err());
+
+    let mut input = "0b11\ntwo words\n\n1 + 1\n".as_bytes();
+    let mut out = Vec::new();
+    numeric::explore(&mut input, &mut out).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    assert!(out.contains("0b11 is 3"));
+    assert!(out.contains("error: expected a type"));
+    // The empty line stops the explorer
+    assert!(!out.contains("1 + 1"));
+}
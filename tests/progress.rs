@@ -0,0 +1,182 @@
+// Learner progress and the progress file
+
+use practice_one::{
+    progress::{self, Kind, Progress},
+    Registry,
+};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{self, Command},
+};
+
+// A progress file of its own for every test, removed again at the end
+struct TempFile(PathBuf);
+
+impl TempFile {
+    fn new(name: &str) -> Self {
+        let path =
+            env::temp_dir().join(format!("practice_one_progress_{}_{}", process::id(), name));
+        fs::remove_file(&path).ok();
+        TempFile(path)
+    }
+
+    fn open(&self, learner: &str) -> Progress {
+        Progress::open(self.0.clone(), learner.to_string()).unwrap()
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        fs::remove_file(&self.0).ok();
+    }
+}
+
+#[test]
+fn records_survive_a_save_and_load() {
+    let file = TempFile::new("round_trip");
+    let mut progress = file.open("script");
+    assert_eq!(progress.records().count(), 0);
+    progress
+        .record(Kind::Lesson, "tut3::ownership", None)
+        .unwrap();
+    progress.record(Kind::Quiz, "tut3", Some(80)).unwrap();
+    progress
+        .record(Kind::Exercise, "tut9_role", Some(100))
+        .unwrap();
+
+    let loaded = file.open("script");
+    let records: Vec<_> = loaded.records().cloned().collect();
+    let written: Vec<_> = progress.records().cloned().collect();
+    assert_eq!(records, written);
+    assert_eq!(records[1].kind, Kind::Quiz);
+    assert_eq!(records[1].score, Some(80));
+    assert_eq!(records[0].score, None);
+
+    let text = fs::read_to_string(&file.0).unwrap();
+    assert!(text.starts_with("# practice_one progress"));
+    assert_eq!(text.lines().count(), 4);
+}
+
+#[test]
+fn learners_only_see_their_own_records() {
+    let file = TempFile::new("learners");
+    file.open("script")
+        .record(Kind::Lesson, "tut2::result", None)
+        .unwrap();
+    file.open("saga")
+        .record(Kind::Lesson, "tut2::option", None)
+        .unwrap();
+
+    let script = file.open("script");
+    assert_eq!(script.records().count(), 1);
+    assert_eq!(script.last_lesson().unwrap().id, "tut2::result");
+}
+
+#[test]
+fn broken_lines_are_skipped() {
+    let file = TempFile::new("broken");
+    fs::write(
+        &file.0,
+        "# header\n1\tscript\tlesson\ttut2::result\t-\nnot a record\n2\tscript\tdance\tx\t-\n",
+    )
+    .unwrap();
+    let progress = file.open("script");
+    assert_eq!(progress.records().count(), 1);
+}
+
+#[test]
+fn a_missing_file_is_an_empty_history_but_other_errors_are_not() {
+    let file = TempFile::new("missing");
+    assert!(file.open("script").last_lesson().is_none());
+    assert!(Progress::open(env::temp_dir(), "script".to_string()).is_err());
+}
+
+#[test]
+fn in_memory_progress_writes_nothing() {
+    let mut progress = Progress::in_memory("script".to_string());
+    progress.record(Kind::Lesson, "tut2::result", None).unwrap();
+    assert_eq!(progress.path(), None);
+    assert_eq!(progress.last_lesson().unwrap().id, "tut2::result");
+}
+
+#[test]
+fn timestamps_are_formatted_as_utc_dates() {
+    assert_eq!(progress::format_timestamp(0), "1970-01-01 00:00 UTC");
+    assert_eq!(
+        progress::format_timestamp(951_782_400),
+        "2000-02-29 00:00 UTC"
+    );
+    assert_eq!(
+        progress::format_timestamp(1_792_316_999),
+        "2026-10-18 09:49 UTC"
+    );
+    assert_eq!(
+        progress::format_timestamp(4_107_542_399),
+        "2100-02-28 23:59 UTC"
+    );
+}
+
+#[test]
+fn resume_goes_to_the_next_lesson_until_the_end() {
+    let registry = Registry::with_all_lessons();
+    let ids: Vec<String> = registry
+        .lessons()
+        .map(|lesson| lesson.qualified_id())
+        .collect();
+
+    let file = TempFile::new("resume");
+    let mut progress = file.open("script");
+    assert_eq!(progress.resume_index(&registry), None);
+
+    progress.record(Kind::Lesson, &ids[0], None).unwrap();
+    assert_eq!(progress.resume_index(&registry), Some(1));
+
+    // Every record has a whole second, so the last lesson has to be the newest
+    let mut finished = Progress::in_memory("script".to_string());
+    finished
+        .record(Kind::Lesson, ids.last().unwrap(), None)
+        .unwrap();
+    assert_eq!(finished.resume_index(&registry), None);
+}
+
+#[test]
+fn the_dashboard_counts_lessons_quizzes_and_exercises() {
+    let registry = Registry::with_all_lessons();
+    let mut progress = Progress::in_memory("script".to_string());
+    progress.record(Kind::Lesson, "tut6::loops", None).unwrap();
+    progress.record(Kind::Quiz, "tut6", Some(50)).unwrap();
+    progress.record(Kind::Quiz, "tut6", Some(75)).unwrap();
+    progress
+        .record(Kind::Exercise, "tut6_fizzbuzz", Some(100))
+        .unwrap();
+
+    let dashboard = progress.dashboard(&registry);
+    assert!(dashboard.starts_with("Progress of script\n"));
+    assert!(dashboard.contains("tut6   lessons 1/2   quiz 75%   exercises 1/1\n"));
+    assert!(dashboard.contains("tut7   lessons 0/2   quiz -   exercises 0/1\n"));
+    assert!(dashboard.contains("\nlast lesson: tut6::loops on "));
+}
+
+fn run_lesson_with_progress(progress: &Path) -> process::Output {
+    Command::new(env!("CARGO_BIN_EXE_practice_one"))
+        .args(["tut2", "result"])
+        .env("PRACTICE_ONE_PROGRESS", progress)
+        .output()
+        .expect("failed to run practice_one")
+}
+
+#[test]
+fn an_unreadable_progress_file_does_not_stop_the_lesson() {
+    // A directory can't be read as a file
+    let dir = env::temp_dir().join(format!("practice_one_progress_dir_{}", process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let output = run_lesson_with_progress(&dir);
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Ok(1)"));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("warning: could not read the progress file"));
+    assert!(stderr.contains("progress won't be saved this time"));
+}
@@ -0,0 +1,177 @@
+// The quiz banks and the quiz runner
+
+use practice_one::{
+    quiz::{self, Answer, Score},
+    Error, Registry,
+};
+
+fn parse_error(bank: &str) -> (usize, String) {
+    match quiz::parse(bank) {
+        Err(Error::Parse { line, message, .. }) => (line, message),
+        other => panic!("expected a parse error, got {:?}", other.map(|_| ())),
+    }
+}
+
+const BANK: &str = "\
+# a comment
+
+== question
+kind: choice
+lesson: strings
+prompt: Pick one
+option: first
+option: second
+answer: 2
+explain: Because.
+
+== question
+kind: prints
+lesson: strings
+prompt: What does this print?
+code:
+    let x = 1;
+    println!(\"{}\", x);
+end
+answer: value is  1
+
+== question
+kind: compiles
+lesson: hash_map
+prompt: Does it compile?
+answer: no
+";
+
+#[test]
+fn banks_are_parsed_into_questions() {
+    let questions = quiz::parse(BANK).unwrap();
+    assert_eq!(questions.len(), 3);
+
+    assert_eq!(questions[0].line, 3);
+    assert_eq!(questions[0].prompt, "Pick one");
+    assert_eq!(questions[0].explanation, "Because.");
+    assert!(matches!(
+        &questions[0].answer,
+        Answer::Choice { options, correct: 2 } if options == &["first", "second"]
+    ));
+
+    assert_eq!(
+        questions[1].code.as_deref(),
+        Some("let x = 1;\nprintln!(\"{}\", x);")
+    );
+    assert!(matches!(&questions[1].answer, Answer::Prints(text) if text == "value is  1"));
+    assert!(questions[1].explanation.is_empty());
+
+    assert_eq!(questions[2].lesson, "hash_map");
+    assert!(matches!(questions[2].answer, Answer::Compiles(false)));
+}
+
+#[test]
+fn errors_point_at_the_line() {
+    assert_eq!(
+        parse_error("kind: choice\n"),
+        (1, "expected `== question` first".to_string())
+    );
+    assert_eq!(
+        parse_error("== question\nkind: choice\nlesson: x\nno colon here\n"),
+        (
+            4,
+            "expected `key: value`, found `no colon here`".to_string()
+        )
+    );
+    assert_eq!(
+        parse_error("== question\n\nsize: 3\n"),
+        (3, "unknown key `size`".to_string())
+    );
+    assert_eq!(
+        parse_error("== question\ncode:\n    let x = 1;\n"),
+        (2, "`code:` without `end`".to_string())
+    );
+    // Problems only found once the question is complete point at its `== question`
+    assert_eq!(
+        parse_error("\n== question\nkind: choice\nlesson: x\nprompt: ?\noption: a\nanswer: 2\n"),
+        (2, "question has answer `2`, but 1 options".to_string())
+    );
+    assert_eq!(
+        parse_error("== question\nkind: riddle\nanswer: yes\n"),
+        (1, "question has unknown kind `riddle`".to_string())
+    );
+    assert_eq!(
+        parse_error("== question\nkind: compiles\nanswer: maybe\n"),
+        (
+            1,
+            "question has answer `maybe`, expected yes or no".to_string()
+        )
+    );
+    assert_eq!(
+        parse_error("== question\nkind: prints\nanswer: 1\nprompt: ?\n"),
+        (1, "question has no `lesson:`".to_string())
+    );
+}
+
+#[test]
+fn every_tutorial_has_a_bank() {
+    let registry = Registry::with_all_lessons();
+    assert_eq!(quiz::tutorials_with_quiz(), registry.tutorials());
+}
+
+#[test]
+fn every_bank_loads_against_the_registry() {
+    let registry = Registry::with_all_lessons();
+    for tutorial in quiz::tutorials_with_quiz() {
+        let questions = quiz::load(&registry, tutorial)
+            .unwrap_or_else(|error| panic!("tut{}: {}", tutorial, error));
+        assert!(
+            questions.len() >= 3,
+            "tut{} has too few questions",
+            tutorial
+        );
+    }
+}
+
+#[test]
+fn tutorials_without_a_bank_are_reported() {
+    let registry = Registry::with_all_lessons();
+    match quiz::load(&registry, 1) {
+        Err(Error::NotFound {
+            what: "quiz",
+            name,
+            available,
+        }) => {
+            assert_eq!(name, "tut1");
+            assert_eq!(available.len(), registry.tutorials().len());
+        }
+        other => panic!("expected a missing quiz, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn answers_are_scored() {
+    let registry = Registry::with_all_lessons();
+    let questions = quiz::parse(BANK).unwrap();
+    let mut input: &[u8] = b"2\nvalue is 1\nyes\n";
+    let mut out = Vec::new();
+    let score = quiz::run(&registry, 10, &questions, &mut input, &mut out).unwrap();
+    let out = String::from_utf8(out).unwrap();
+
+    assert_eq!(
+        score,
+        Score {
+            correct: 2,
+            total: 3
+        }
+    );
+    assert_eq!(score.percent(), 66);
+    assert!(out.contains("Question 1: Pick one\n  1. first\n  2. second\n"));
+    assert!(out.contains("Not quite, the answer is: no, it doesn't compile"));
+    assert!(out.contains("See tut10::understanding_rust_strings (practice_one tut10 strings)"));
+    assert!(out.ends_with("Score: 2/3 (66%)\n"));
+}
+
+#[test]
+fn running_out_of_input_counts_as_wrong() {
+    let registry = Registry::with_all_lessons();
+    let questions = quiz::parse(BANK).unwrap();
+    let mut input: &[u8] = b"2\n";
+    let score = quiz::run(&registry, 10, &questions, &mut input, &mut Vec::new()).unwrap();
+    assert_eq!((score.correct, score.total), (1, 3));
+}
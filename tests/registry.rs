@@ -0,0 +1,81 @@
+// Finding lessons through the registry
+
+use practice_one::{Lesson, LessonInfo, Registry};
+use std::{collections::HashSet, io};
+
+fn nothing(_: &mut dyn io::Write) -> io::Result<()> {
+    Ok(())
+}
+
+fn lesson(tutorial: u8, id: &'static str) -> LessonInfo {
+    LessonInfo {
+        id,
+        title: "A test lesson",
+        tutorial,
+        tags: &[],
+        entry_name: id,
+        entry: nothing,
+        diagrams: &[],
+    }
+}
+
+fn ids<'a>(lessons: impl Iterator<Item = &'a dyn Lesson>) -> Vec<String> {
+    lessons.map(|lesson| lesson.qualified_id()).collect()
+}
+
+#[test]
+fn tutorials_come_in_registration_order() {
+    let mut registry = Registry::new();
+    assert!(registry.tutorials().is_empty());
+
+    registry.register(lesson(3, "heap"));
+    registry.register(lesson(3, "stack"));
+    registry.register(lesson(2, "result"));
+    assert_eq!(registry.tutorials(), [3, 2]);
+
+    assert_eq!(
+        Registry::with_all_lessons().tutorials(),
+        [2, 3, 4, 5, 6, 7, 8, 9, 10, 11]
+    );
+}
+
+#[test]
+fn lessons_in_only_gives_that_tutorial() {
+    let mut registry = Registry::new();
+    registry.register(lesson(2, "result"));
+    registry.register(lesson(3, "heap"));
+    registry.register(lesson(2, "option"));
+
+    assert_eq!(
+        ids(registry.lessons_in(2)),
+        ["tut2::result", "tut2::option"]
+    );
+    assert_eq!(ids(registry.lessons_in(3)), ["tut3::heap"]);
+    assert!(registry.lessons_in(4).next().is_none());
+}
+
+#[test]
+fn find_needs_the_right_tutorial_and_id() {
+    let mut registry = Registry::new();
+    registry.register(lesson(2, "result"));
+    registry.register(lesson(3, "result"));
+
+    assert_eq!(registry.find(3, "result").unwrap().tutorial(), 3);
+    assert!(registry.find(2, "heap").is_none());
+    assert!(registry.find(4, "result").is_none());
+}
+
+#[test]
+fn every_lesson_has_a_unique_qualified_id() {
+    let registry = Registry::with_all_lessons();
+    let all = ids(registry.lessons());
+    let unique: HashSet<&String> = all.iter().collect();
+    assert_eq!(unique.len(), all.len());
+
+    for tutorial in registry.tutorials() {
+        for lesson in registry.lessons_in(tutorial) {
+            let found = registry.find(tutorial, lesson.id()).unwrap();
+            assert_eq!(found.qualified_id(), lesson.qualified_id());
+        }
+    }
+}
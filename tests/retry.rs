@@ -0,0 +1,157 @@
+// Retry and fallback helpers, with a clock that records the delays instead of sleeping
+
+use practice_one::{
+    lessons::tut2,
+    retry::{AllFailed, Backoff, Clock, Fallback, Retry},
+    Error,
+};
+use std::{cell::RefCell, io, time::Duration};
+
+#[derive(Default)]
+struct RecordingClock {
+    sleeps: RefCell<Vec<Duration>>,
+}
+
+impl Clock for RecordingClock {
+    fn sleep(&self, duration: Duration) {
+        self.sleeps.borrow_mut().push(duration);
+    }
+}
+
+// Fails with `failures` interrupted errors before it succeeds
+fn flaky(failures: u32) -> impl FnMut() -> io::Result<u32> {
+    let mut calls = 0;
+    move || {
+        calls += 1;
+        if calls <= failures {
+            Err(io::Error::new(
+                io::ErrorKind::Interrupted,
+                format!("call {}", calls),
+            ))
+        } else {
+            Ok(calls)
+        }
+    }
+}
+
+fn millis(values: &[u64]) -> Vec<Duration> {
+    values
+        .iter()
+        .map(|value| Duration::from_millis(*value))
+        .collect()
+}
+
+#[test]
+fn succeeds_once_the_operation_recovers() {
+    let clock = RecordingClock::default();
+    let retry = Retry::new(5)
+        .backoff(Backoff::Fixed(Duration::from_millis(10)))
+        .clock(&clock);
+
+    assert_eq!(retry.run(flaky(2)).unwrap(), 3);
+    assert_eq!(*clock.sleeps.borrow(), millis(&[10, 10]));
+}
+
+#[test]
+fn collects_every_error_when_all_attempts_fail() {
+    let clock = RecordingClock::default();
+    let retry = Retry::new(3).clock(&clock);
+
+    let failed = retry.run(flaky(10)).unwrap_err();
+    let messages: Vec<String> = failed
+        .errors
+        .iter()
+        .map(|error| error.to_string())
+        .collect();
+    assert_eq!(messages, ["call 1", "call 2", "call 3"]);
+    assert_eq!(
+        failed.to_string(),
+        "all 3 attempts failed: 1: call 1; 2: call 2; 3: call 3"
+    );
+    assert_eq!(*clock.sleeps.borrow(), millis(&[0, 0]));
+}
+
+#[test]
+fn exponential_backoff_doubles_up_to_the_maximum() {
+    let backoff = Backoff::exponential(Duration::from_millis(10), Duration::from_millis(50));
+    let delays: Vec<Duration> = (1..=5).map(|retry| backoff.delay(retry)).collect();
+    assert_eq!(delays, millis(&[10, 20, 40, 50, 50]));
+
+    // Large retry numbers don't overflow
+    assert_eq!(backoff.delay(u32::MAX), Duration::from_millis(50));
+
+    let clock = RecordingClock::default();
+    Retry::new(4)
+        .backoff(backoff)
+        .clock(&clock)
+        .run(flaky(3))
+        .unwrap();
+    assert_eq!(*clock.sleeps.borrow(), millis(&[10, 20, 40]));
+}
+
+#[test]
+fn run_if_stops_on_errors_that_are_not_worth_retrying() {
+    let clock = RecordingClock::default();
+    let mut calls = 0;
+    let result: Result<(), AllFailed<io::Error>> = Retry::new(5).clock(&clock).run_if(
+        || {
+            calls += 1;
+            Err(io::Error::from(io::ErrorKind::NotFound))
+        },
+        |error| error.kind() == io::ErrorKind::Interrupted,
+    );
+
+    assert_eq!(result.unwrap_err().errors.len(), 1);
+    assert_eq!(calls, 1);
+    assert!(clock.sleeps.borrow().is_empty());
+}
+
+#[test]
+fn zero_attempts_still_tries_once() {
+    let mut calls = 0;
+    let result: Result<u32, AllFailed<()>> = Retry::new(0).run(|| {
+        calls += 1;
+        Ok(calls)
+    });
+    assert_eq!(result.unwrap(), 1);
+}
+
+#[test]
+fn fallback_takes_the_first_alternative_that_works() {
+    let count = Fallback::new(|| tut2::parse_follower_count("many"))
+        .then(|| tut2::parse_follower_count("-3"))
+        .then(|| tut2::parse_follower_count("12"))
+        .run();
+    assert!(matches!(count, Ok(12)));
+
+    let failed = Fallback::new(|| tut2::parse_follower_count("many"))
+        .then(|| tut2::parse_follower_count("-3"))
+        .run()
+        .unwrap_err();
+    assert_eq!(failed.errors.len(), 2);
+    assert!(
+        matches!(failed.last(), Some(Error::Validation(message)) if message.contains("negative"))
+    );
+}
+
+#[test]
+fn fallback_ends_in_a_default() {
+    let count = Fallback::new(|| tut2::parse_follower_count("many")).run_or(0);
+    assert_eq!(count, 0);
+
+    let attempts = Fallback::new(|| tut2::parse_follower_count("x"))
+        .then(|| tut2::parse_follower_count("y"))
+        .run_or_else(|failed| failed.errors.len() as i32);
+    assert_eq!(attempts, 2);
+
+    // Later steps never run once one succeeds
+    let mut ran_second = false;
+    let first = Fallback::new(tut2::function_return_a_result)
+        .then(|| {
+            ran_second = true;
+            Ok(0)
+        })
+        .run_or(-1);
+    assert_eq!(first, 1);
+    assert!(!ran_second);
+}
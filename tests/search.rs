@@ -0,0 +1,74 @@
+// Full-text search over the tutorials
+
+use practice_one::{
+    search::{self, Index},
+    tutorial_source, Registry,
+};
+
+fn names(index: &Index, query: &str) -> Vec<String> {
+    index
+        .search(query)
+        .iter()
+        .map(|hit| hit.document.qualified_name())
+        .collect()
+}
+
+#[test]
+fn query_words_are_split_like_the_index() {
+    assert_eq!(
+        search::query_words("HashMap::new"),
+        ["hashmap", "hash", "map", "new"]
+    );
+    assert_eq!(
+        search::query_words("deref_coercion deref*"),
+        ["deref_coercion", "deref", "coercion"]
+    );
+    assert_eq!(search::query_words("what is a lifetime"), ["lifetime"]);
+}
+
+#[test]
+fn hits_are_ranked_best_first() {
+    let index = Index::build(&Registry::with_all_lessons());
+    let hits = index.search("borrow");
+    assert!(hits.len() > 1);
+    assert!(hits.windows(2).all(|pair| pair[0].score >= pair[1].score));
+    assert!(index.search("zzzzqqq").is_empty());
+}
+
+#[test]
+fn a_path_finds_the_lesson_about_it() {
+    let index = Index::build(&Registry::with_all_lessons());
+    assert_eq!(
+        names(&index, "HashMap::new")[0],
+        "tut10::understanding_hash_map"
+    );
+}
+
+#[test]
+fn punctuation_and_spaces_between_words_search_the_same() {
+    let index = Index::build(&Registry::with_all_lessons());
+    let dashed = names(&index, "deref-coercion");
+    assert_eq!(dashed, names(&index, "deref coercion"));
+    assert!(dashed[..2].contains(&"tut10::check_deref_coercion".to_string()));
+}
+
+#[test]
+fn hits_point_at_the_line_of_their_item() {
+    let index = Index::build(&Registry::with_all_lessons());
+    let hit = index
+        .search("check_deref_coercion")
+        .into_iter()
+        .find(|hit| hit.document.name.as_deref() == Some("check_deref_coercion"))
+        .unwrap();
+
+    let tut10 = tutorial_source(10).unwrap();
+    let line = tut10.lines().nth(hit.document.line - 1).unwrap();
+    assert!(line.contains("fn check_deref_coercion("), "{}", line);
+
+    let words = search::query_words("check_deref_coercion");
+    let formatted = search::format_hit(&hit, &words);
+    assert!(formatted.starts_with(&format!(
+        "src/lessons/tut10.rs:{}  tut10::check_deref_coercion  (",
+        hit.document.line
+    )));
+}
@@ -0,0 +1,145 @@
+// Golden-file tests for the output of every lesson
+//
+// Each lesson is run through the practice_one binary and its stdout is compared with
+// tests/snapshots/<tutorial>_<lesson>.txt. When a lesson's output changes on purpose, re-bless the
+// snapshots with:
+//
+//     BLESS=1 cargo test --test snapshots
+//
+// The snapshots are recorded with the default features. Without count-allocations the binary can't
+// count, so only the lines before alloc_counter::COUNTED are compared and the counted part has to
+// be the line saying counting is off.
+
+use practice_one::alloc_counter::COUNTED;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{self, Command},
+};
+
+fn practice_one(progress: &Path, args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_practice_one"))
+        .args(args)
+        .env("PRACTICE_ONE_PROGRESS", progress)
+        .output()
+        .expect("failed to run practice_one");
+    assert!(
+        output.status.success(),
+        "practice_one {:?} failed:\n{}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).expect("lesson output is not UTF-8")
+}
+
+fn snapshot_path(qualified_id: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(format!("{}.txt", qualified_id.replace("::", "_")))
+}
+
+// Line diff based on the longest common subsequence, good enough for lesson sized outputs
+fn diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+
+    let mut lcs = vec![vec![0usize; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            lcs[i][j] = if expected[i] == actual[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut report = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            report.push_str(&format!("  {}\n", expected[i]));
+            i += 1;
+            j += 1;
+        } else if i < expected.len() && (j == actual.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            report.push_str(&format!("- {}\n", expected[i]));
+            i += 1;
+        } else {
+            report.push_str(&format!("+ {}\n", actual[j]));
+            j += 1;
+        }
+    }
+    report
+}
+
+// What a snapshot recorded with counting looks like when counting is off, the counted part is
+// replaced by the line saying so
+fn without_counting(expected: &str) -> String {
+    let mut kept = String::new();
+    for line in expected.lines() {
+        if line == COUNTED {
+            kept.push_str(COUNTED);
+            kept.push_str(" off, build with the count-allocations feature to see them\n");
+            break;
+        }
+        kept.push_str(line);
+        kept.push('\n');
+    }
+    kept
+}
+
+#[test]
+fn lesson_output_matches_snapshots() {
+    let bless = env::var_os("BLESS").is_some();
+    let counting = cfg!(feature = "count-allocations");
+    assert!(
+        counting || !bless,
+        "bless the snapshots with the default features, they include the counted allocations"
+    );
+    // Keep the lessons run here out of the real progress file, in a file of this run only
+    let progress =
+        env::temp_dir().join(format!("practice_one_snapshots_progress_{}", process::id()));
+    fs::remove_file(&progress).ok();
+    let ids = practice_one(&progress, &["list", "--ids"]);
+    let mut failures = Vec::new();
+
+    for qualified_id in ids.lines() {
+        let (tutorial, lesson) = qualified_id.split_once("::").unwrap();
+        let actual = practice_one(&progress, &[tutorial, lesson]);
+        let path = snapshot_path(qualified_id);
+
+        if bless {
+            fs::write(&path, &actual).unwrap();
+            continue;
+        }
+
+        let expected = fs::read_to_string(&path).map(|expected| {
+            if counting {
+                expected
+            } else {
+                without_counting(&expected)
+            }
+        });
+        match expected {
+            Ok(expected) if expected == actual => {}
+            Ok(expected) => failures.push(format!(
+                "{} does not match {}\n{}",
+                qualified_id,
+                path.display(),
+                diff(&expected, &actual)
+            )),
+            Err(_) => failures.push(format!(
+                "{} has no snapshot at {}",
+                qualified_id,
+                path.display()
+            )),
+        }
+    }
+
+    fs::remove_file(&progress).ok();
+    assert!(
+        failures.is_empty(),
+        "{}\nrun `BLESS=1 cargo test --test snapshots` to accept the new output",
+        failures.join("\n")
+    );
+}
@@ -0,0 +1,95 @@
+// Tracked<T> records the life of a value in its timeline
+
+use practice_one::tracked::{Event, EventKind, Timeline, Tracked};
+
+fn steps(timeline: &Timeline) -> Vec<String> {
+    timeline
+        .events()
+        .iter()
+        .map(|event| format!("{}: {}", event.scope, event))
+        .collect()
+}
+
+fn consume(value: Tracked<Vec<i32>>) -> usize {
+    value.len()
+}
+
+#[test]
+fn records_creation_moves_and_drops_in_order() {
+    let timeline = Timeline::new();
+    {
+        let data = Tracked::new(&timeline, "data", "outer", vec![1, 2, 3]);
+        let moved = data.moved_to("moved", "outer");
+        assert_eq!(moved.name(), "moved");
+        assert_eq!(consume(moved.moved_to("value", "consume")), 3);
+    }
+
+    assert_eq!(
+        steps(&timeline),
+        [
+            "outer: created data",
+            "outer: moved data to moved",
+            "consume: moved moved to value",
+            "consume: dropped value",
+        ]
+    );
+}
+
+#[test]
+fn clones_are_separate_values() {
+    let timeline = Timeline::new();
+    {
+        let original = Tracked::new(&timeline, "original", "main", String::from("x"));
+        let named = original.clone_as("named");
+        let unnamed = original.clone();
+        assert_eq!(*named, *unnamed);
+        assert_eq!(unnamed.name(), "original.clone()");
+    }
+
+    let events = timeline.events();
+    assert_eq!(
+        events[1],
+        Event {
+            name: "named".to_string(),
+            scope: "main".to_string(),
+            kind: EventKind::Cloned {
+                from: "original".to_string()
+            },
+        }
+    );
+    // Locals are dropped in reverse order of declaration
+    let drops: Vec<&str> = events
+        .iter()
+        .filter(|event| event.kind == EventKind::Dropped)
+        .map(|event| event.name.as_str())
+        .collect();
+    assert_eq!(drops, ["original.clone()", "named", "original"]);
+}
+
+#[test]
+fn values_in_a_collection_drop_with_it() {
+    let timeline = Timeline::new();
+    let values: Vec<Tracked<u8>> = (0..2)
+        .map(|number| Tracked::new(&timeline, &format!("v{}", number), "vec", number))
+        .collect();
+    assert_eq!(timeline.events().len(), 2);
+
+    drop(values);
+    assert_eq!(
+        steps(&timeline)[2..],
+        ["vec: dropped v0", "vec: dropped v1"]
+    );
+}
+
+#[test]
+fn write_numbers_every_step() {
+    let timeline = Timeline::new();
+    drop(Tracked::new(&timeline, "short", "block", ()));
+
+    let mut out = Vec::new();
+    timeline.write(&mut out).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "  1. block  created short\n  2. block  dropped short\n"
+    );
+}